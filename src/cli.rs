@@ -44,6 +44,19 @@ pub fn execute_cmd(cfg: DsConfig, opts: MainOpts) -> Result<(), CmdError> {
         SubCommand::View(input) => input.exec(&ctx)?,
         SubCommand::Cleanup(input) => input.exec(&ctx)?,
         SubCommand::Export(input) => input.exec(&ctx)?,
+        SubCommand::Org(input) => input.exec(&ctx)?,
+        SubCommand::Person(input) => input.exec(&ctx)?,
+        SubCommand::Equipment(input) => input.exec(&ctx)?,
+        SubCommand::Folder(input) => input.exec(&ctx)?,
+        SubCommand::Field(input) => input.exec(&ctx)?,
+        SubCommand::Tag(input) => input.exec(&ctx)?,
+        SubCommand::Attachment(input) => input.exec(&ctx)?,
+        SubCommand::Queue(input) => input.exec(&ctx)?,
+        SubCommand::Share(input) => input.exec(&ctx)?,
+        SubCommand::Notify(input) => input.exec(&ctx)?,
+        SubCommand::PeriodicQuery(input) => input.exec(&ctx)?,
+        SubCommand::MailSettings(input) => input.exec(&ctx)?,
+        SubCommand::ScanMailbox(input) => input.exec(&ctx)?,
     };
     Ok(())
 }
@@ -10,21 +10,34 @@
 //! referenced in the subcommand enum.
 
 pub mod admin;
+pub mod attachment;
 pub mod bookmark;
 pub mod cleanup;
 pub mod download;
+pub mod equipment;
 pub mod export;
+pub mod field;
 pub mod file_exists;
+pub mod folder;
 pub mod generate_completions;
 pub mod geninvite;
 pub mod item;
 pub mod login;
 pub mod logout;
+pub mod mail_settings;
+pub mod notify;
 pub mod open_item;
+pub mod org;
+pub mod periodic_query;
+pub mod person;
+pub mod queue;
 pub mod register;
+pub mod scan_mailbox;
 pub mod search;
 pub mod search_summary;
+pub mod share;
 pub mod source;
+pub mod tag;
 pub mod upload;
 pub mod version;
 pub mod view;
@@ -176,6 +189,9 @@ pub enum CmdError {
     #[snafu(display("OpenItem - {}", source))]
     OpenItem { source: open_item::Error },
 
+    #[snafu(display("Org - {}", source))]
+    Org { source: org::Error },
+
     #[snafu(display("Register - {}", source))]
     Register { source: register::Error },
 
@@ -194,6 +210,42 @@ pub enum CmdError {
     #[snafu(display("View - {}", source))]
     View { source: view::Error },
 
+    #[snafu(display("Person - {}", source))]
+    Person { source: person::Error },
+
+    #[snafu(display("Equipment - {}", source))]
+    Equipment { source: equipment::Error },
+
+    #[snafu(display("Folder - {}", source))]
+    Folder { source: folder::Error },
+
+    #[snafu(display("Field - {}", source))]
+    Field { source: field::Error },
+
+    #[snafu(display("Tag - {}", source))]
+    Tag { source: tag::Error },
+
+    #[snafu(display("Attachment - {}", source))]
+    Attachment { source: attachment::Error },
+
+    #[snafu(display("Queue - {}", source))]
+    Queue { source: queue::Error },
+
+    #[snafu(display("Share - {}", source))]
+    Share { source: share::Error },
+
+    #[snafu(display("Notify - {}", source))]
+    Notify { source: notify::Error },
+
+    #[snafu(display("PeriodicQuery - {}", source))]
+    PeriodicQuery { source: periodic_query::Error },
+
+    #[snafu(display("MailSettings - {}", source))]
+    MailSettings { source: mail_settings::Error },
+
+    #[snafu(display("ScanMailbox - {}", source))]
+    ScanMailbox { source: scan_mailbox::Error },
+
     #[snafu(display("WriteConfig - {}", source))]
     WriteConfig { source: ConfigError },
 
@@ -302,5 +354,70 @@ impl From<export::Error> for CmdError {
         CmdError::Export { source }
     }
 }
+impl From<org::Error> for CmdError {
+    fn from(source: org::Error) -> Self {
+        CmdError::Org { source }
+    }
+}
+impl From<person::Error> for CmdError {
+    fn from(source: person::Error) -> Self {
+        CmdError::Person { source }
+    }
+}
+impl From<equipment::Error> for CmdError {
+    fn from(source: equipment::Error) -> Self {
+        CmdError::Equipment { source }
+    }
+}
+impl From<folder::Error> for CmdError {
+    fn from(source: folder::Error) -> Self {
+        CmdError::Folder { source }
+    }
+}
+impl From<field::Error> for CmdError {
+    fn from(source: field::Error) -> Self {
+        CmdError::Field { source }
+    }
+}
+impl From<tag::Error> for CmdError {
+    fn from(source: tag::Error) -> Self {
+        CmdError::Tag { source }
+    }
+}
+impl From<attachment::Error> for CmdError {
+    fn from(source: attachment::Error) -> Self {
+        CmdError::Attachment { source }
+    }
+}
+impl From<queue::Error> for CmdError {
+    fn from(source: queue::Error) -> Self {
+        CmdError::Queue { source }
+    }
+}
+impl From<share::Error> for CmdError {
+    fn from(source: share::Error) -> Self {
+        CmdError::Share { source }
+    }
+}
+impl From<notify::Error> for CmdError {
+    fn from(source: notify::Error) -> Self {
+        CmdError::Notify { source }
+    }
+}
+impl From<periodic_query::Error> for CmdError {
+    fn from(source: periodic_query::Error) -> Self {
+        CmdError::PeriodicQuery { source }
+    }
+}
+impl From<mail_settings::Error> for CmdError {
+    fn from(source: mail_settings::Error) -> Self {
+        CmdError::MailSettings { source }
+    }
+}
+impl From<scan_mailbox::Error> for CmdError {
+    fn from(source: scan_mailbox::Error) -> Self {
+        CmdError::ScanMailbox { source }
+    }
+}
 
 const DSC_DOCSPELL_URL: &str = "DSC_DOCSPELL_URL";
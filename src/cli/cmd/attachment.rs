@@ -0,0 +1,76 @@
+pub mod delete;
+pub mod meta;
+pub mod move_before;
+pub mod move_to_item;
+pub mod rename;
+
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::http::Error as HttpError;
+
+/// Manage attachments of items.
+///
+/// Attachments are given by their complete id. If the item is given
+/// via `--item`, the id can be abbreviated to a prefix or the
+/// attachment name can be used instead.
+#[derive(Parser, std::fmt::Debug)]
+pub struct Input {
+    #[command(subcommand)]
+    pub subcmd: AttachmentCommand,
+}
+
+#[derive(Parser, Debug)]
+pub enum AttachmentCommand {
+    #[command(version)]
+    Rename(rename::Input),
+
+    #[command(version)]
+    Delete(delete::Input),
+
+    #[command(version)]
+    MoveBefore(move_before::Input),
+
+    #[command(version)]
+    MoveToItem(move_to_item::Input),
+
+    #[command(version)]
+    Meta(meta::Input),
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    Rename { source: rename::Error },
+    Delete { source: delete::Error },
+    MoveBefore { source: move_before::Error },
+    MoveToItem { source: move_to_item::Error },
+    Meta { source: meta::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        match &self.subcmd {
+            AttachmentCommand::Rename(input) => input.exec(ctx).context(RenameSnafu),
+            AttachmentCommand::Delete(input) => input.exec(ctx).context(DeleteSnafu),
+            AttachmentCommand::MoveBefore(input) => input.exec(ctx).context(MoveBeforeSnafu),
+            AttachmentCommand::MoveToItem(input) => input.exec(ctx).context(MoveToItemSnafu),
+            AttachmentCommand::Meta(input) => input.exec(ctx).context(MetaSnafu),
+        }
+    }
+}
+
+/// Returns the complete id of an attachment. If an item is given, the
+/// attachment is looked up in this item by a prefix of its id or its
+/// name. Otherwise `id` is expected to be complete.
+fn resolve_id(ctx: &Context, item: &Option<String>, id: &str) -> Result<String, HttpError> {
+    match item {
+        Some(item_id) => ctx
+            .client
+            .find_attachment(&ctx.opts.session, item_id, id)
+            .map(|a| a.id),
+        None => Ok(id.to_string()),
+    }
+}
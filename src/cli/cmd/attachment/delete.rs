@@ -0,0 +1,43 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::Error as HttpError;
+
+/// Delete an attachment.
+///
+/// The attachment is removed from the item, including all its files.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The attachment to delete.
+    pub id: String,
+
+    /// The item containing the attachment (can be abbreviated to a
+    /// prefix).
+    #[arg(long)]
+    pub item: Option<String>,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let id = super::resolve_id(ctx, &self.item, &self.id).context(HttpClientSnafu)?;
+        let result = ctx
+            .client
+            .delete_attachment(&ctx.opts.session, &id)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
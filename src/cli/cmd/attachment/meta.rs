@@ -0,0 +1,49 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::opts::Format;
+use crate::cli::sink::Error as SinkError;
+use crate::http::Error as HttpError;
+
+/// Show the extracted text and metadata of an attachment.
+///
+/// In tabular format, only the extracted text is printed. Other
+/// formats print the complete metadata, including labels and
+/// proposals.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The attachment id.
+    pub id: String,
+
+    /// The item containing the attachment (can be abbreviated to a
+    /// prefix).
+    #[arg(long)]
+    pub item: Option<String>,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let id = super::resolve_id(ctx, &self.item, &self.id).context(HttpClientSnafu)?;
+        let meta = ctx
+            .client
+            .get_attachment_meta(&ctx.opts.session, &id)
+            .context(HttpClientSnafu)?;
+        match ctx.format() {
+            Format::Tabular => println!("{}", meta.content),
+            _ => ctx.write_result(meta).context(WriteResultSnafu)?,
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::Error as HttpError;
+
+/// Change the position of an attachment within its item.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The attachment to move.
+    pub id: String,
+
+    /// The attachment that should follow the moved one.
+    #[arg(long)]
+    pub before: String,
+
+    /// The item containing both attachments (can be abbreviated to a
+    /// prefix).
+    #[arg(long)]
+    pub item: String,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let item = Some(self.item.clone());
+        let source = super::resolve_id(ctx, &item, &self.id).context(HttpClientSnafu)?;
+        let target = super::resolve_id(ctx, &item, &self.before).context(HttpClientSnafu)?;
+        let result = ctx
+            .client
+            .move_attachment_before(&ctx.opts.session, &self.item, &source, &target)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
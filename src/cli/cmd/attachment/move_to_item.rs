@@ -0,0 +1,77 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::{DownloadAuth, DownloadRef, Error as HttpError};
+
+/// Move an attachment to another item.
+///
+/// The original file of the attachment is uploaded to the target item
+/// and the attachment is deleted once the upload succeeded. The file
+/// is processed again as part of the target item.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The attachment to move.
+    pub id: String,
+
+    /// The item containing the attachment (can be abbreviated to a
+    /// prefix).
+    #[arg(long)]
+    pub item: Option<String>,
+
+    /// The item to move the attachment to (can be abbreviated to a
+    /// prefix).
+    #[arg(long)]
+    pub to: String,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+
+    #[snafu(display("The attachment was not found: {}", id))]
+    AttachmentNotFound { id: String },
+
+    #[snafu(display("Uploading the attachment to '{}' failed: {}", item, message))]
+    Upload { item: String, message: String },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let id = super::resolve_id(ctx, &self.item, &self.id).context(HttpClientSnafu)?;
+        let dref = DownloadRef {
+            id: id.clone(),
+            name: id.clone(),
+        };
+        let file = dref
+            .get_original(&ctx.client, &DownloadAuth::from_session(&ctx.opts.session))
+            .context(HttpClientSnafu)?
+            .ok_or_else(|| Error::AttachmentNotFound { id: id.clone() })?;
+        let name = file.get_filename().unwrap_or_else(|| id.clone());
+
+        let uploaded = ctx
+            .client
+            .upload_to_item(&ctx.opts.session, &self.to, &name, file)
+            .context(HttpClientSnafu)?;
+        if !uploaded.success {
+            return Err(Error::Upload {
+                item: self.to.clone(),
+                message: uploaded.message,
+            });
+        }
+        let deleted = ctx
+            .client
+            .delete_attachment(&ctx.opts.session, &id)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(vec![uploaded, deleted])
+            .context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
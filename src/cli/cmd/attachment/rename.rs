@@ -0,0 +1,49 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::Error as HttpError;
+
+/// Rename an attachment.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The attachment to rename.
+    pub id: String,
+
+    /// The item containing the attachment (can be abbreviated to a
+    /// prefix).
+    #[arg(long)]
+    pub item: Option<String>,
+
+    /// The new name.
+    #[arg(long, required_unless_present = "clear")]
+    pub name: Option<String>,
+
+    /// Remove the name of the attachment.
+    #[arg(long, conflicts_with = "name")]
+    pub clear: bool,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let id = super::resolve_id(ctx, &self.item, &self.id).context(HttpClientSnafu)?;
+        let result = ctx
+            .client
+            .rename_attachment(&ctx.opts.session, &id, self.name.as_deref())
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
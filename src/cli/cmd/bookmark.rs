@@ -1,4 +1,8 @@
+pub mod add;
+pub mod delete;
 pub mod get;
+pub mod run;
+pub mod update;
 
 use clap::Parser;
 use snafu::{ResultExt, Snafu};
@@ -16,11 +20,27 @@ pub struct Input {
 pub enum BookmarkCommand {
     #[command(version)]
     Get(get::Input),
+
+    #[command(version)]
+    Add(add::Input),
+
+    #[command(version)]
+    Update(update::Input),
+
+    #[command(version)]
+    Delete(delete::Input),
+
+    #[command(version)]
+    Run(run::Input),
 }
 
 #[derive(Debug, Snafu)]
 pub enum Error {
     Get { source: get::Error },
+    Add { source: add::Error },
+    Update { source: update::Error },
+    Delete { source: delete::Error },
+    Run { source: run::Error },
 }
 
 impl Cmd for Input {
@@ -29,6 +49,10 @@ impl Cmd for Input {
     fn exec(&self, ctx: &Context) -> Result<(), Error> {
         match &self.subcmd {
             BookmarkCommand::Get(input) => input.exec(ctx).context(GetSnafu),
+            BookmarkCommand::Add(input) => input.exec(ctx).context(AddSnafu),
+            BookmarkCommand::Update(input) => input.exec(ctx).context(UpdateSnafu),
+            BookmarkCommand::Delete(input) => input.exec(ctx).context(DeleteSnafu),
+            BookmarkCommand::Run(input) => input.exec(ctx).context(RunSnafu),
         }
     }
 }
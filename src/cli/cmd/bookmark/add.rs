@@ -0,0 +1,60 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::Bookmark;
+use crate::http::Error as HttpError;
+
+/// Create a new query bookmark.
+///
+/// Bookmarks are shared with the collective, unless `--personal` is
+/// given.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The name of the bookmark.
+    #[arg(long)]
+    pub name: String,
+
+    /// The query to store. See <https://docspell.org/docs/query/>
+    #[arg(long)]
+    pub query: String,
+
+    /// An optional label that is shown instead of the name.
+    #[arg(long)]
+    pub label: Option<String>,
+
+    /// Make the bookmark visible only to yourself.
+    #[arg(long)]
+    pub personal: bool,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let bookmark = Bookmark {
+            id: "".into(),
+            name: self.name.clone(),
+            label: self.label.clone(),
+            query: self.query.clone(),
+            personal: self.personal,
+            created: 0,
+        };
+        let result = ctx
+            .client
+            .create_bookmark(&ctx.opts.session, &bookmark)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use clap::{ArgAction, Parser};
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::cmd::search;
+use crate::cli::opts::SearchMode;
+use crate::http::Error as HttpError;
+
+/// Runs the query of a bookmark and prints the results.
+///
+/// This is the same as running `search` with the stored query.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The bookmark to run, given by its id (can be abbreviated to a
+    /// prefix) or name.
+    pub name: String,
+
+    #[clap(flatten)]
+    pub search_mode: SearchMode,
+
+    /// Do not fetch details to each item in the result
+    #[arg(long = "no-details", action = ArgAction::SetFalse)]
+    pub with_details: bool,
+
+    /// Limit the number of results.
+    #[arg(short, long, default_value = "20")]
+    pub limit: u32,
+
+    /// Skip the first n results.
+    #[arg(short, long, default_value = "0")]
+    pub offset: u32,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("{}", source))]
+    Search { source: search::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let bookmark = ctx
+            .client
+            .find_bookmark(&ctx.opts.session, &self.name)
+            .context(HttpClientSnafu)?;
+        let search = search::Input {
+            query: bookmark.query,
+            search_mode: self.search_mode.clone(),
+            with_details: self.with_details,
+            limit: self.limit,
+            offset: self.offset,
+        };
+        search.exec(ctx).context(SearchSnafu)
+    }
+}
@@ -0,0 +1,73 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::Error as HttpError;
+
+/// Change a query bookmark.
+///
+/// Only the given properties are changed, all others are kept.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The bookmark to change, given by its id (can be abbreviated to
+    /// a prefix) or name.
+    #[arg(long)]
+    pub id: String,
+
+    /// Set a new name.
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Set a new query. See <https://docspell.org/docs/query/>
+    #[arg(long)]
+    pub query: Option<String>,
+
+    /// Set a new label.
+    #[arg(long)]
+    pub label: Option<String>,
+
+    /// Whether the bookmark is only visible to yourself.
+    #[arg(long)]
+    pub personal: Option<bool>,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let mut bookmark = ctx
+            .client
+            .find_bookmark(&ctx.opts.session, &self.id)
+            .context(HttpClientSnafu)?;
+
+        if let Some(name) = &self.name {
+            bookmark.name = name.clone();
+        }
+        if let Some(query) = &self.query {
+            bookmark.query = query.clone();
+        }
+        if let Some(label) = &self.label {
+            bookmark.label = Some(label.clone());
+        }
+        if let Some(personal) = self.personal {
+            bookmark.personal = personal;
+        }
+
+        let result = ctx
+            .client
+            .update_bookmark(&ctx.opts.session, &bookmark)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
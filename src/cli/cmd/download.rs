@@ -6,7 +6,7 @@ use super::{Cmd, Context};
 use crate::http::payload::SearchReq;
 use crate::{
     cli::opts::SearchMode,
-    http::{Downloads, Error as HttpError},
+    http::{Download, DownloadAuth, DownloadRef, Downloads, Error as HttpError},
     util::dupes::Dupes,
 };
 
@@ -14,8 +14,8 @@ use crate::{
 ///
 /// Searches for documents via a query and downloads all associated
 /// files. It downloads by default the converted PDF files, which can
-/// be changed using options `--original` and `--archive`,
-/// respectively.
+/// be changed using options `--original`, `--archive` and
+/// `--preview`, respectively.
 ///
 /// Use the `search-summary` command with the same query to get an
 /// idea how much is being downloaded.
@@ -53,6 +53,11 @@ pub struct Input {
     #[arg(long, group = "kind")]
     archive: bool,
 
+    /// Download the preview image of each attachment, which shows its
+    /// first page.
+    #[arg(long, group = "kind")]
+    preview: bool,
+
     /// Creates a single zip file containing all files (flat). If this
     /// is enabled, the `target` option is expected to be the target
     /// zip file and not a directory.
@@ -71,12 +76,51 @@ pub struct Input {
     #[arg(short, long)]
     target: Option<PathBuf>,
 }
+
+/// The options for downloading the files of a search result from
+/// other commands, like `share fetch`. Only the converted files or
+/// preview images can be chosen.
+#[derive(Debug, Clone)]
+pub struct DownloadOpts {
+    pub query: String,
+    pub limit: u32,
+    pub offset: u32,
+    pub overwrite: bool,
+    pub preview: bool,
+    pub zip: bool,
+    pub dupes: DupeMode,
+    pub target: Option<PathBuf>,
+}
+
+impl From<DownloadOpts> for Input {
+    fn from(opts: DownloadOpts) -> Self {
+        Input {
+            query: opts.query,
+            search_mode: SearchMode {
+                all: false,
+                trashed_only: false,
+            },
+            limit: opts.limit,
+            offset: opts.offset,
+            overwrite: opts.overwrite,
+            original: false,
+            archive: false,
+            preview: opts.preview,
+            zip: opts.zip,
+            dupes: opts.dupes,
+            target: opts.target,
+        }
+    }
+}
+
 impl Input {
     fn download_type(&self) -> &'static str {
         if self.original {
             "original"
         } else if self.archive {
             "archive"
+        } else if self.preview {
+            "preview"
         } else {
             "attachment"
         }
@@ -122,49 +166,60 @@ impl Cmd for Input {
             query: self.query.clone(),
             search_mode: self.search_mode.to_mode(),
         };
+        let auth = DownloadAuth::from_session(&ctx.opts.session);
         let attachs = ctx
             .client
-            .download_search(&ctx.opts.session, &req)
+            .download_search(&auth, &req)
             .context(HttpClientSnafu)?;
+        download_files(attachs, self, ctx, &auth)
+    }
+}
 
-        if attachs.is_empty() {
-            println!("The search result is empty.");
-            Ok(())
-        } else {
-            match self.zip {
-                true => {
-                    let zip_file = self
-                        .target
-                        .clone()
-                        .unwrap_or_else(|| PathBuf::from("docspell-files.zip"));
-                    if let Some(parent) = zip_file.parent() {
-                        if !parent.exists() {
-                            std::fs::create_dir_all(&parent).context(CreateFileSnafu)?;
-                        }
-                    }
-                    println!(
-                        "Zipping {}",
-                        action_msg(self, attachs.len(), zip_file.display())
-                    );
-
-                    download_zip(attachs, self, ctx, &zip_file)
-                }
-                false => {
-                    let parent = self
-                        .target
-                        .clone()
-                        .unwrap_or(std::env::current_dir().context(CreateFileSnafu)?);
-
+/// Downloads the given attachments according to the options, either
+/// into a directory or a zip file.
+pub fn download_files(
+    attachs: Downloads,
+    opts: &Input,
+    ctx: &Context,
+    auth: &DownloadAuth,
+) -> Result<(), Error> {
+    if attachs.is_empty() {
+        println!("The search result is empty.");
+        Ok(())
+    } else {
+        match opts.zip {
+            true => {
+                let zip_file = opts
+                    .target
+                    .clone()
+                    .unwrap_or_else(|| PathBuf::from("docspell-files.zip"));
+                if let Some(parent) = zip_file.parent() {
                     if !parent.exists() {
                         std::fs::create_dir_all(&parent).context(CreateFileSnafu)?;
                     }
-                    println!(
-                        "Downloading {}",
-                        action_msg(self, attachs.len(), parent.display())
-                    );
+                }
+                println!(
+                    "Zipping {}",
+                    action_msg(opts, attachs.len(), zip_file.display())
+                );
 
-                    download_flat(attachs, self, ctx, &parent)
+                download_zip(attachs, opts, ctx, auth, &zip_file)
+            }
+            false => {
+                let parent = opts
+                    .target
+                    .clone()
+                    .unwrap_or(std::env::current_dir().context(CreateFileSnafu)?);
+
+                if !parent.exists() {
+                    std::fs::create_dir_all(&parent).context(CreateFileSnafu)?;
                 }
+                println!(
+                    "Downloading {}",
+                    action_msg(opts, attachs.len(), parent.display())
+                );
+
+                download_flat(attachs, opts, ctx, auth, &parent)
             }
         }
     }
@@ -174,21 +229,15 @@ fn download_flat(
     attachs: Downloads,
     opts: &Input,
     ctx: &Context,
+    auth: &DownloadAuth,
     parent: &Path,
 ) -> Result<(), Error> {
     let mut dupes = Dupes::new();
     for dref in attachs {
-        let dlopt = if opts.original {
-            dref.get_original(&ctx.client, &ctx.opts.session)
-        } else if opts.archive {
-            dref.get_archive(&ctx.client, &ctx.opts.session)
-        } else {
-            dref.get(&ctx.client, &ctx.opts.session)
-        }
-        .context(HttpClientSnafu)?;
+        let dlopt = get_file(&dref, opts, ctx, auth)?;
 
         if let Some(mut dl) = dlopt {
-            let org_name = dl.get_filename().unwrap_or(dref.name);
+            let org_name = file_name(&dl, &dref, opts);
             let (fname, duplicate) = dupes.use_name(&org_name);
             let path = parent.join(&fname);
             if path.exists() && !opts.overwrite {
@@ -216,6 +265,7 @@ fn download_zip(
     attachs: Downloads,
     opts: &Input,
     ctx: &Context,
+    auth: &DownloadAuth,
     zip_file: &Path,
 ) -> Result<(), Error> {
     if zip_file.exists() && !opts.overwrite {
@@ -228,17 +278,10 @@ fn download_zip(
         let mut zw = zip::ZipWriter::new(zip);
         let mut dupes = Dupes::new();
         for dref in attachs {
-            let dlopt = if opts.original {
-                dref.get_original(&ctx.client, &ctx.opts.session)
-            } else if opts.archive {
-                dref.get_archive(&ctx.client, &ctx.opts.session)
-            } else {
-                dref.get(&ctx.client, &ctx.opts.session)
-            }
-            .context(HttpClientSnafu)?;
+            let dlopt = get_file(&dref, opts, ctx, auth)?;
 
             if let Some(mut dl) = dlopt {
-                let org_name = dl.get_filename().unwrap_or(dref.name);
+                let org_name = file_name(&dl, &dref, opts);
                 let (fname, duplicate) = dupes.use_name(&org_name);
                 if duplicate && opts.dupes == DupeMode::Skip {
                     println!("Skipping already downloaded file {}", org_name);
@@ -268,7 +311,51 @@ fn download_zip(
     Ok(())
 }
 
-fn check_args(args: &Input) -> Result<(), Error> {
+fn get_file(
+    dref: &DownloadRef,
+    opts: &Input,
+    ctx: &Context,
+    auth: &DownloadAuth,
+) -> Result<Option<Download>, Error> {
+    if opts.original {
+        dref.get_original(&ctx.client, auth)
+    } else if opts.archive {
+        dref.get_archive(&ctx.client, auth)
+    } else if opts.preview {
+        dref.get_preview(&ctx.client, auth)
+    } else {
+        dref.get(&ctx.client, auth)
+    }
+    .context(HttpClientSnafu)
+}
+
+/// Returns the name of the downloaded file. Preview images are named
+/// after their attachment, if the server doesn't provide a name.
+fn file_name(dl: &Download, dref: &DownloadRef, opts: &Input) -> String {
+    match dl.get_filename() {
+        Some(name) => name,
+        None if opts.preview => preview_file_name(&dref.name, dl),
+        None => dref.name.clone(),
+    }
+}
+
+/// Creates a file name for a preview image from the given name by
+/// replacing its extension with one matching the content type.
+pub fn preview_file_name(name: &str, dl: &Download) -> String {
+    let ext = match dl.get_content_type().as_deref() {
+        Some("image/png") => "png",
+        _ => "jpg",
+    };
+    let stem = Path::new(name)
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| name.to_string());
+    format!("{}.{}", stem, ext)
+}
+
+/// Checks that the target is a directory, or a file when using
+/// `--zip`.
+pub fn check_args(args: &Input) -> Result<(), Error> {
     match &args.target {
         Some(path) => {
             if args.zip && path.exists() && path.is_dir() {
@@ -288,6 +375,8 @@ fn action_msg(opts: &Input, len: usize, target: Display) -> String {
         format!("original files of {} attachments into {}", len, target)
     } else if opts.archive {
         format!("archives of {} attachments into {}", len, target)
+    } else if opts.preview {
+        format!("previews of {} attachments into {}", len, target)
     } else {
         format!("{} attachments into {}", len, target)
     }
@@ -0,0 +1,69 @@
+pub mod add;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+use clap::{Parser, ValueEnum};
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+
+/// Manage equipments.
+#[derive(Parser, std::fmt::Debug)]
+pub struct Input {
+    #[command(subcommand)]
+    pub subcmd: EquipmentCommand,
+}
+
+#[derive(Parser, Debug)]
+pub enum EquipmentCommand {
+    #[command(version)]
+    List(list::Input),
+
+    #[command(version)]
+    Add(add::Input),
+
+    #[command(version)]
+    Update(update::Input),
+
+    #[command(version)]
+    Delete(delete::Input),
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    List { source: list::Error },
+    Add { source: add::Error },
+    Update { source: update::Error },
+    Delete { source: delete::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        match &self.subcmd {
+            EquipmentCommand::List(input) => input.exec(ctx).context(ListSnafu),
+            EquipmentCommand::Add(input) => input.exec(ctx).context(AddSnafu),
+            EquipmentCommand::Update(input) => input.exec(ctx).context(UpdateSnafu),
+            EquipmentCommand::Delete(input) => input.exec(ctx).context(DeleteSnafu),
+        }
+    }
+}
+
+/// Defines how an equipment is used.
+#[derive(ValueEnum, Debug, Clone)]
+pub enum EquipmentUse {
+    /// The equipment can be used as concerning equipment.
+    Concerning,
+    /// The equipment is hidden from suggestions.
+    Disabled,
+}
+impl EquipmentUse {
+    pub fn to_value(&self) -> &'static str {
+        match self {
+            EquipmentUse::Concerning => "concerning",
+            EquipmentUse::Disabled => "disabled",
+        }
+    }
+}
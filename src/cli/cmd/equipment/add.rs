@@ -0,0 +1,52 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context, EquipmentUse};
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::Equipment;
+use crate::http::Error as HttpError;
+
+/// Create a new equipment.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The name of the equipment.
+    #[arg(long)]
+    pub name: String,
+
+    /// Some notes about the equipment.
+    #[arg(long)]
+    pub notes: Option<String>,
+
+    /// How the equipment is used.
+    #[arg(long = "use", value_enum, default_value = "concerning")]
+    pub equip_use: EquipmentUse,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let equip = Equipment {
+            id: "".into(),
+            name: self.name.clone(),
+            notes: self.notes.clone(),
+            equip_use: self.equip_use.to_value().into(),
+            created: 0,
+        };
+        let result = ctx
+            .client
+            .create_equipment(&ctx.opts.session, &equip)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
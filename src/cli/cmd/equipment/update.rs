@@ -0,0 +1,66 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context, EquipmentUse};
+use crate::cli::sink::Error as SinkError;
+use crate::http::Error as HttpError;
+
+/// Change properties of an equipment.
+///
+/// Only the given properties are changed, all others are kept.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The equipment to change, given by its id (can be abbreviated
+    /// to a prefix) or name.
+    #[arg(long)]
+    pub id: String,
+
+    /// Set a new name.
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Set new notes.
+    #[arg(long)]
+    pub notes: Option<String>,
+
+    /// Change how the equipment is used.
+    #[arg(long = "use", value_enum)]
+    pub equip_use: Option<EquipmentUse>,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let mut equip = ctx
+            .client
+            .find_equipment(&ctx.opts.session, &self.id)
+            .context(HttpClientSnafu)?;
+
+        if let Some(name) = &self.name {
+            equip.name = name.clone();
+        }
+        if let Some(notes) = &self.notes {
+            equip.notes = Some(notes.clone());
+        }
+        if let Some(equip_use) = &self.equip_use {
+            equip.equip_use = equip_use.to_value().into();
+        }
+
+        let result = ctx
+            .client
+            .update_equipment(&ctx.opts.session, &equip)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -7,7 +7,7 @@ use crate::cli::opts::Format;
 use crate::cli::sink::Error as SinkError;
 use crate::cli::table::format_date_by;
 use crate::http::payload::{Item, SearchMode, SearchReq};
-use crate::http::{Downloads, Error as HttpError};
+use crate::http::{DownloadAuth, Downloads, Error as HttpError};
 use crate::util::file;
 
 #[derive(ValueEnum, Clone, Copy, Debug)]
@@ -105,6 +105,36 @@ pub struct Input {
     query: Option<String>,
 }
 
+/// The options for exporting a search result from other commands,
+/// like `share fetch`. No symlinks are created.
+#[derive(Debug, Clone)]
+pub struct ExportOpts {
+    pub query: Option<String>,
+    pub limit: u32,
+    pub offset: u32,
+    pub all: bool,
+    pub overwrite: bool,
+    pub target: PathBuf,
+}
+
+impl From<ExportOpts> for Input {
+    fn from(opts: ExportOpts) -> Self {
+        Input {
+            limit: opts.limit,
+            offset: opts.offset,
+            all: opts.all,
+            overwrite: opts.overwrite,
+            link_naming: None,
+            date_links: false,
+            tag_links: false,
+            folder_links: false,
+            correspondent_links: false,
+            folder_delimiter: None,
+            target: opts.target,
+            query: opts.query,
+        }
+    }
+}
 #[derive(Debug, Snafu)]
 pub enum Error {
     #[snafu(display("An http error occurred: {}", source))]
@@ -133,34 +163,44 @@ impl Cmd for Input {
     type CmdError = Error;
 
     fn exec(&self, ctx: &Context) -> Result<(), Error> {
-        let mut req = SearchReq {
-            offset: self.offset,
-            limit: self.limit,
-            with_details: true,
-            query: self.query.clone().unwrap_or_else(|| "".into()),
-            search_mode: SearchMode::Normal,
-        };
-
-        let mut counter = 0;
-        loop {
-            let next = export(&req, self, ctx)?;
-            counter += next;
-            if self.all && next >= self.limit as usize {
-                req.offset += req.limit;
-            } else {
-                break;
-            }
-        }
-        eprintln!("Exported {} items.", counter);
+        export_all(self, ctx, &DownloadAuth::from_session(&ctx.opts.session))?;
         Ok(())
     }
 }
 
-fn export(req: &SearchReq, opts: &Input, ctx: &Context) -> Result<usize, Error> {
-    let results = ctx
-        .client
-        .search(&ctx.opts.session, req)
-        .context(HttpClientSnafu)?;
+/// Exports the items matching the query of the given options and
+/// returns their number. The `auth` is used for searching and
+/// downloading files.
+pub fn export_all(opts: &Input, ctx: &Context, auth: &DownloadAuth) -> Result<usize, Error> {
+    let mut req = SearchReq {
+        offset: opts.offset,
+        limit: opts.limit,
+        with_details: true,
+        query: opts.query.clone().unwrap_or_else(|| "".into()),
+        search_mode: SearchMode::Normal,
+    };
+
+    let mut counter = 0;
+    loop {
+        let next = export(&req, opts, ctx, auth)?;
+        counter += next;
+        if opts.all && next >= opts.limit as usize {
+            req.offset += req.limit;
+        } else {
+            break;
+        }
+    }
+    eprintln!("Exported {} items.", counter);
+    Ok(counter)
+}
+
+fn export(
+    req: &SearchReq,
+    opts: &Input,
+    ctx: &Context,
+    auth: &DownloadAuth,
+) -> Result<usize, Error> {
+    let results = ctx.client.search_with(auth, req).context(HttpClientSnafu)?;
     let mut item_counter = 0;
     let items = opts.target.join("items");
     let by_date = opts.target.join("by_date");
@@ -171,7 +211,7 @@ fn export(req: &SearchReq, opts: &Input, ctx: &Context) -> Result<usize, Error>
         for item in g.items {
             item_counter += 1;
             let item_dir = items.join(&item.id[0..2]).join(&item.id);
-            export_item(&item, opts.overwrite, &item_dir, ctx)?;
+            export_item(&item, opts.overwrite, &item_dir, ctx, auth)?;
 
             if opts.date_links {
                 let link_dir = by_date.join(format_date_by(item.date, "%Y-%m"));
@@ -216,7 +256,13 @@ fn export_message(item: Item, ctx: &Context) -> Result<(), Error> {
     Ok(())
 }
 
-fn export_item(item: &Item, overwrite: bool, item_dir: &Path, ctx: &Context) -> Result<(), Error> {
+fn export_item(
+    item: &Item,
+    overwrite: bool,
+    item_dir: &Path,
+    ctx: &Context,
+    auth: &DownloadAuth,
+) -> Result<(), Error> {
     log::debug!("Exporting item {}/{}", item.id, item.name);
     let meta_file = item_dir.join("metadata.json");
     if meta_file.exists() && overwrite {
@@ -244,9 +290,12 @@ fn export_item(item: &Item, overwrite: bool, item_dir: &Path, ctx: &Context) ->
     let dl = Downloads::from_item(item);
     for attach in dl {
         log::debug!("Saving attachment: {}/{}", attach.id, attach.name);
-        let orig = attach
-            .get_original(&ctx.client, &ctx.opts.session)
-            .context(HttpClientSnafu)?;
+        // shares don't provide the original file, use the converted one
+        let orig = match auth {
+            DownloadAuth::Session { .. } => attach.get_original(&ctx.client, auth),
+            DownloadAuth::Share { .. } => attach.get(&ctx.client, auth),
+        }
+        .context(HttpClientSnafu)?;
         if let Some(mut orig_file) = orig {
             let file_name = orig_file.get_filename().unwrap_or(attach.name);
             let file_path = file_dir.join(file_name);
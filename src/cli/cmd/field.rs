@@ -0,0 +1,76 @@
+pub mod add;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+use clap::{Parser, ValueEnum};
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+
+/// Manage custom field definitions.
+///
+/// Custom fields must be defined before values can be set on items
+/// via `item fields`.
+#[derive(Parser, std::fmt::Debug)]
+pub struct Input {
+    #[command(subcommand)]
+    pub subcmd: FieldCommand,
+}
+
+#[derive(Parser, Debug)]
+pub enum FieldCommand {
+    #[command(version)]
+    List(list::Input),
+
+    #[command(version)]
+    Add(add::Input),
+
+    #[command(version)]
+    Update(update::Input),
+
+    #[command(version)]
+    Delete(delete::Input),
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    List { source: list::Error },
+    Add { source: add::Error },
+    Update { source: update::Error },
+    Delete { source: delete::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        match &self.subcmd {
+            FieldCommand::List(input) => input.exec(ctx).context(ListSnafu),
+            FieldCommand::Add(input) => input.exec(ctx).context(AddSnafu),
+            FieldCommand::Update(input) => input.exec(ctx).context(UpdateSnafu),
+            FieldCommand::Delete(input) => input.exec(ctx).context(DeleteSnafu),
+        }
+    }
+}
+
+/// The type of values a custom field accepts.
+#[derive(ValueEnum, Debug, Clone)]
+pub enum FieldType {
+    Text,
+    Numeric,
+    Money,
+    Date,
+    Bool,
+}
+impl FieldType {
+    pub fn to_value(&self) -> &'static str {
+        match self {
+            FieldType::Text => "text",
+            FieldType::Numeric => "numeric",
+            FieldType::Money => "money",
+            FieldType::Date => "date",
+            FieldType::Bool => "bool",
+        }
+    }
+}
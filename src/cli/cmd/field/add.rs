@@ -0,0 +1,51 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context, FieldType};
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::NewCustomField;
+use crate::http::Error as HttpError;
+
+/// Create a new custom field.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The name of the field. It is used to refer to the field, for
+    /// example in queries.
+    #[arg(long)]
+    pub name: String,
+
+    /// An optional label that is shown instead of the name.
+    #[arg(long)]
+    pub label: Option<String>,
+
+    /// The type of the field values.
+    #[arg(long, value_enum)]
+    pub ftype: FieldType,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let field = NewCustomField {
+            name: self.name.clone(),
+            label: self.label.clone(),
+            ftype: self.ftype.to_value().into(),
+        };
+        let result = ctx
+            .client
+            .create_custom_field(&ctx.opts.session, &field)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
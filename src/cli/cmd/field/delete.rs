@@ -0,0 +1,42 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::Error as HttpError;
+
+/// Delete a custom field.
+///
+/// This also removes all values of this field from all items.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The field to delete, given by its id (can be abbreviated to a
+    /// prefix) or name.
+    pub id: String,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let field = ctx
+            .client
+            .find_custom_field(&ctx.opts.session, &self.id)
+            .context(HttpClientSnafu)?;
+        let result = ctx
+            .client
+            .delete_custom_field(&ctx.opts.session, &field.id)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
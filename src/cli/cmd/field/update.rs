@@ -0,0 +1,65 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context, FieldType};
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::NewCustomField;
+use crate::http::Error as HttpError;
+
+/// Change a custom field.
+///
+/// Only the given properties are changed, all others are kept.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The field to change, given by its id (can be abbreviated to a
+    /// prefix) or name.
+    #[arg(long)]
+    pub id: String,
+
+    /// Set a new name.
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Set a new label.
+    #[arg(long)]
+    pub label: Option<String>,
+
+    /// Change the type of the field values.
+    #[arg(long, value_enum)]
+    pub ftype: Option<FieldType>,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let field = ctx
+            .client
+            .find_custom_field(&ctx.opts.session, &self.id)
+            .context(HttpClientSnafu)?;
+        let data = NewCustomField {
+            name: self.name.clone().unwrap_or(field.name),
+            label: self.label.clone().or(field.label),
+            ftype: self
+                .ftype
+                .as_ref()
+                .map(|t| t.to_value().to_string())
+                .unwrap_or(field.ftype),
+        };
+        let result = ctx
+            .client
+            .update_custom_field(&ctx.opts.session, &field.id, &data)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+pub mod add;
+pub mod delete;
+pub mod list;
+pub mod member;
+pub mod rename;
+
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+
+/// Manage folders and their members.
+#[derive(Parser, std::fmt::Debug)]
+pub struct Input {
+    #[command(subcommand)]
+    pub subcmd: FolderCommand,
+}
+
+#[derive(Parser, Debug)]
+pub enum FolderCommand {
+    #[command(version)]
+    List(list::Input),
+
+    #[command(version)]
+    Add(add::Input),
+
+    #[command(version)]
+    Rename(rename::Input),
+
+    #[command(version)]
+    Delete(delete::Input),
+
+    #[command(version)]
+    Member(member::Input),
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    List { source: list::Error },
+    Add { source: add::Error },
+    Rename { source: rename::Error },
+    Delete { source: delete::Error },
+    Member { source: member::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        match &self.subcmd {
+            FolderCommand::List(input) => input.exec(ctx).context(ListSnafu),
+            FolderCommand::Add(input) => input.exec(ctx).context(AddSnafu),
+            FolderCommand::Rename(input) => input.exec(ctx).context(RenameSnafu),
+            FolderCommand::Delete(input) => input.exec(ctx).context(DeleteSnafu),
+            FolderCommand::Member(input) => input.exec(ctx).context(MemberSnafu),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::NewFolder;
+use crate::http::Error as HttpError;
+
+/// Create a new folder.
+///
+/// The folder is owned by the current user. Other users of the
+/// collective can be added as members using `folder member add`.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The name of the folder.
+    #[arg(long)]
+    pub name: String,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let folder = NewFolder {
+            name: self.name.clone(),
+        };
+        let result = ctx
+            .client
+            .create_folder(&ctx.opts.session, &folder)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+pub mod add;
+pub mod list;
+pub mod remove;
+
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+
+/// Manage the members of a folder.
+#[derive(Parser, std::fmt::Debug)]
+pub struct Input {
+    #[command(subcommand)]
+    pub subcmd: MemberCommand,
+}
+
+#[derive(Parser, Debug)]
+pub enum MemberCommand {
+    #[command(version)]
+    List(list::Input),
+
+    #[command(version)]
+    Add(add::Input),
+
+    #[command(version)]
+    Remove(remove::Input),
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    List { source: list::Error },
+    Add { source: add::Error },
+    Remove { source: remove::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        match &self.subcmd {
+            MemberCommand::List(input) => input.exec(ctx).context(ListSnafu),
+            MemberCommand::Add(input) => input.exec(ctx).context(AddSnafu),
+            MemberCommand::Remove(input) => input.exec(ctx).context(RemoveSnafu),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::Error as HttpError;
+
+/// List the members of a folder.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The folder, given by its id (can be abbreviated to a prefix)
+    /// or name.
+    #[arg(long)]
+    pub folder: String,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let folder = ctx
+            .client
+            .find_folder(&ctx.opts.session, &self.folder)
+            .context(HttpClientSnafu)?;
+        let detail = ctx
+            .client
+            .get_folder(&ctx.opts.session, &folder.id)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(detail).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
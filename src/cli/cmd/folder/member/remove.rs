@@ -0,0 +1,53 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::Error as HttpError;
+
+/// Remove members from a folder.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The folder, given by its id (can be abbreviated to a prefix)
+    /// or name.
+    #[arg(long)]
+    pub folder: String,
+
+    /// The users to remove, given by their login or id.
+    #[arg(required = true, num_args = 1)]
+    pub users: Vec<String>,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let folder = ctx
+            .client
+            .find_folder(&ctx.opts.session, &self.folder)
+            .context(HttpClientSnafu)?;
+        let mut results = Vec::new();
+        for login in &self.users {
+            let user = ctx
+                .client
+                .find_user(&ctx.opts.session, login)
+                .context(HttpClientSnafu)?;
+            let result = ctx
+                .client
+                .remove_folder_member(&ctx.opts.session, &folder.id, &user.id)
+                .context(HttpClientSnafu)?;
+            results.push(result);
+        }
+        ctx.write_result(results).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::NewFolder;
+use crate::http::Error as HttpError;
+
+/// Change the name of a folder.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The folder to rename, given by its id (can be abbreviated to a
+    /// prefix) or name.
+    #[arg(long)]
+    pub id: String,
+
+    /// The new name of the folder.
+    #[arg(long)]
+    pub name: String,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let folder = ctx
+            .client
+            .find_folder(&ctx.opts.session, &self.id)
+            .context(HttpClientSnafu)?;
+        let data = NewFolder {
+            name: self.name.clone(),
+        };
+        let result = ctx
+            .client
+            .rename_folder(&ctx.opts.session, &folder.id, &data)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -1,11 +1,28 @@
+pub mod confirm;
+pub mod delete;
+pub mod empty_trash;
 pub mod fields;
 pub mod get;
+pub mod merge;
+pub mod notes;
+pub mod preview;
+pub mod proposals;
+pub mod reprocess;
+pub mod restore;
+pub mod send;
+pub mod sent_mails;
+pub mod set;
 pub mod tags;
+pub mod text;
+pub mod unconfirm;
 
 use clap::Parser;
 use snafu::{ResultExt, Snafu};
 
 use super::{Cmd, Context};
+use crate::cli::opts::ItemSelect;
+use crate::http::payload::{BasicResult, SearchMode};
+use crate::http::Error as HttpError;
 
 /// Manage items.
 #[derive(Parser, std::fmt::Debug)]
@@ -24,6 +41,48 @@ pub enum ItemCommand {
 
     #[command(version)]
     Fields(fields::Input),
+
+    #[command(version)]
+    Set(set::Input),
+
+    #[command(version)]
+    Notes(notes::Input),
+
+    #[command(version)]
+    Confirm(confirm::Input),
+
+    #[command(version)]
+    Unconfirm(unconfirm::Input),
+
+    #[command(version)]
+    Delete(delete::Input),
+
+    #[command(version)]
+    Restore(restore::Input),
+
+    #[command(version)]
+    EmptyTrash(empty_trash::Input),
+
+    #[command(version)]
+    Merge(merge::Input),
+
+    #[command(version)]
+    Reprocess(reprocess::Input),
+
+    #[command(version)]
+    Text(text::Input),
+
+    #[command(version)]
+    Preview(preview::Input),
+
+    #[command(version)]
+    Proposals(proposals::Input),
+
+    #[command(version)]
+    Send(send::Input),
+
+    #[command(version)]
+    SentMails(sent_mails::Input),
 }
 
 #[derive(Debug, Snafu)]
@@ -31,6 +90,20 @@ pub enum Error {
     Get { source: get::Error },
     Tags { source: tags::Error },
     Fields { source: fields::Error },
+    Set { source: set::Error },
+    Notes { source: notes::Error },
+    Confirm { source: confirm::Error },
+    Unconfirm { source: unconfirm::Error },
+    Delete { source: delete::Error },
+    Restore { source: restore::Error },
+    EmptyTrash { source: empty_trash::Error },
+    Merge { source: merge::Error },
+    Reprocess { source: reprocess::Error },
+    Text { source: text::Error },
+    Preview { source: preview::Error },
+    Proposals { source: proposals::Error },
+    Send { source: send::Error },
+    SentMails { source: sent_mails::Error },
 }
 
 impl Cmd for Input {
@@ -41,6 +114,38 @@ impl Cmd for Input {
             ItemCommand::Get(input) => input.exec(ctx).context(GetSnafu),
             ItemCommand::Tags(input) => input.exec(ctx).context(TagsSnafu),
             ItemCommand::Fields(input) => input.exec(ctx).context(FieldsSnafu),
+            ItemCommand::Set(input) => input.exec(ctx).context(SetSnafu),
+            ItemCommand::Notes(input) => input.exec(ctx).context(NotesSnafu),
+            ItemCommand::Confirm(input) => input.exec(ctx).context(ConfirmSnafu),
+            ItemCommand::Unconfirm(input) => input.exec(ctx).context(UnconfirmSnafu),
+            ItemCommand::Delete(input) => input.exec(ctx).context(DeleteSnafu),
+            ItemCommand::Restore(input) => input.exec(ctx).context(RestoreSnafu),
+            ItemCommand::EmptyTrash(input) => input.exec(ctx).context(EmptyTrashSnafu),
+            ItemCommand::Merge(input) => input.exec(ctx).context(MergeSnafu),
+            ItemCommand::Reprocess(input) => input.exec(ctx).context(ReprocessSnafu),
+            ItemCommand::Text(input) => input.exec(ctx).context(TextSnafu),
+            ItemCommand::Preview(input) => input.exec(ctx).context(PreviewSnafu),
+            ItemCommand::Proposals(input) => input.exec(ctx).context(ProposalsSnafu),
+            ItemCommand::Send(input) => input.exec(ctx).context(SendSnafu),
+            ItemCommand::SentMails(input) => input.exec(ctx).context(SentMailsSnafu),
         }
     }
 }
+
+/// Resolves the selected items and applies `f` to each of them,
+/// collecting all results.
+fn for_each_item<F>(
+    items: &ItemSelect,
+    search_mode: SearchMode,
+    ctx: &Context,
+    f: F,
+) -> Result<Vec<BasicResult>, HttpError>
+where
+    F: Fn(&str) -> Result<BasicResult, HttpError>,
+{
+    items
+        .resolve_ids(&ctx.client, &ctx.opts.session, search_mode)?
+        .iter()
+        .map(|id| f(id.as_str()))
+        .collect()
+}
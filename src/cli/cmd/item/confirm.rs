@@ -0,0 +1,40 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::opts::ItemSelect;
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::SearchMode;
+use crate::http::Error as HttpError;
+
+/// Confirm items.
+///
+/// Confirmed items are considered reviewed and don't show up in the
+/// inbox anymore.
+#[derive(Parser, Debug)]
+pub struct Input {
+    #[clap(flatten)]
+    pub items: ItemSelect,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let results = super::for_each_item(&self.items, SearchMode::Normal, ctx, |id| {
+            ctx.client.confirm_item(&ctx.opts.session, id)
+        })
+        .context(HttpClientSnafu)?;
+        ctx.write_result(results).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::Error as HttpError;
+
+/// Permanently delete items in the trash.
+///
+/// This submits a background task on the server. Deleted items cannot
+/// be restored.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// Only delete items that have been in the trash for at least
+    /// this many days.
+    #[arg(long, default_value = "0")]
+    pub min_age_days: u32,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let min_age = i64::from(self.min_age_days) * 24 * 60 * 60 * 1000;
+        let result = ctx
+            .client
+            .empty_trash(&ctx.opts.session, min_age)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+use clap::Parser;
+use dialoguer::Confirm;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::{BasicResult, ItemDetail};
+use crate::http::Error as HttpError;
+
+/// Merge several items into one.
+///
+/// The attachments and metadata of all other items are added to the
+/// target item. The other items are deleted afterwards. A preview of
+/// the resulting attachments is shown before asking for confirmation.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The item to merge into (can be abbreviated to a prefix)
+    pub target: String,
+
+    /// The items to merge into the target (can be abbreviated to a
+    /// prefix)
+    #[arg(required = true, num_args = 1..)]
+    pub others: Vec<String>,
+
+    /// Don't ask for confirmation.
+    #[arg(long, short)]
+    pub yes: bool,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+
+    #[snafu(display("Interaction with terminal failed: {}", source))]
+    Interact { source: dialoguer::Error },
+
+    #[snafu(display("The item was not found: {}", id))]
+    ItemNotFound { id: String },
+
+    #[snafu(display("Cannot merge item '{}' into itself", id))]
+    SameItem { id: String },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let target = get_item(&self.target, ctx)?;
+        let mut items = vec![target];
+        for id in &self.others {
+            let item = get_item(id, ctx)?;
+            if item.id == items[0].id {
+                return Err(Error::SameItem { id: item.id });
+            }
+            // different prefixes may resolve to the same item
+            if items.iter().all(|i| i.id != item.id) {
+                items.push(item);
+            }
+        }
+
+        print_preview(&items);
+        let result = if self.yes || confirm()? {
+            let ids: Vec<&str> = items.iter().map(|i| i.id.as_str()).collect();
+            ctx.client
+                .merge_items(&ctx.opts.session, &ids)
+                .context(HttpClientSnafu)?
+        } else {
+            BasicResult {
+                success: false,
+                message: "Merge cancelled.".into(),
+            }
+        };
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
+
+fn get_item(id: &str, ctx: &Context) -> Result<ItemDetail, Error> {
+    ctx.client
+        .get_item(&ctx.opts.session, id)
+        .context(HttpClientSnafu)?
+        .ok_or_else(|| Error::ItemNotFound { id: id.to_string() })
+}
+
+fn print_preview(items: &[ItemDetail]) {
+    let target = &items[0];
+    eprintln!("Merging into '{}' ({}):", target.name, &target.id[0..8]);
+    let mut n = 1;
+    for item in items {
+        for attach in &item.attachments {
+            let name = attach.name.as_deref().unwrap_or(&attach.id);
+            eprintln!("  {}. {} (from '{}')", n, name, item.name);
+            n += 1;
+        }
+    }
+}
+
+fn confirm() -> Result<bool, Error> {
+    let answer = Confirm::new()
+        .with_prompt("Merge these items?")
+        .default(false)
+        .interact_opt()
+        .context(InteractSnafu)?;
+    Ok(answer.unwrap_or(false))
+}
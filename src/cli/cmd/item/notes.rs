@@ -0,0 +1,131 @@
+use std::io::Read;
+
+use clap::{ArgGroup, Parser};
+use dialoguer::Editor;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::BasicResult;
+use crate::http::Error as HttpError;
+
+/// Edit the notes of an item.
+///
+/// Without any options, the current notes are opened in `$EDITOR`
+/// and saved if they have been changed. Empty notes are removed from
+/// the item.
+#[derive(Parser, Debug)]
+#[command(group = ArgGroup::new("source"))]
+pub struct Input {
+    /// The item id (can be abbreviated to a prefix)
+    pub id: String,
+
+    /// Replace the notes with the given text.
+    #[arg(long, group = "source")]
+    pub set: Option<String>,
+
+    /// Append the given text to the current notes.
+    #[arg(long, group = "source")]
+    pub append: Option<String>,
+
+    /// Replace the notes with the text read from stdin.
+    #[arg(long, group = "source")]
+    pub stdin: bool,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+
+    #[snafu(display("Error reading from stdin: {}", source))]
+    ReadStdin { source: std::io::Error },
+
+    #[snafu(display("Error running the editor: {}", source))]
+    Edit { source: dialoguer::Error },
+
+    #[snafu(display("The item was not found"))]
+    ItemNotFound,
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let item = ctx
+            .client
+            .get_item(&ctx.opts.session, &self.id)
+            .context(HttpClientSnafu)?
+            .ok_or(Error::ItemNotFound)?;
+        let current = item.notes.unwrap_or_default();
+        let result = match self.new_notes(&current)? {
+            Some(notes) if notes != current => {
+                let notes = Some(notes.as_str()).filter(|n| !n.trim().is_empty());
+                ctx.client
+                    .set_item_notes(&ctx.opts.session, &item.id, notes)
+                    .context(HttpClientSnafu)?
+            }
+            _ => BasicResult {
+                success: true,
+                message: "Notes unchanged.".into(),
+            },
+        };
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
+
+impl Input {
+    /// Returns the new notes, or `None` if the editor was closed
+    /// without saving.
+    fn new_notes(&self, current: &str) -> Result<Option<String>, Error> {
+        if let Some(text) = &self.set {
+            Ok(Some(text.clone()))
+        } else if let Some(text) = &self.append {
+            if current.trim().is_empty() {
+                Ok(Some(text.clone()))
+            } else {
+                Ok(Some(format!("{}\n{}", current.trim_end(), text)))
+            }
+        } else if self.stdin {
+            let mut text = String::new();
+            std::io::stdin()
+                .read_to_string(&mut text)
+                .context(ReadStdinSnafu)?;
+            Ok(Some(text))
+        } else {
+            Editor::new().edit(current).context(EditSnafu)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(set: Option<&str>, append: Option<&str>) -> Input {
+        Input {
+            id: "id".into(),
+            set: set.map(String::from),
+            append: append.map(String::from),
+            stdin: false,
+        }
+    }
+
+    #[test]
+    fn unit_new_notes_set() {
+        let notes = input(Some("new"), None).new_notes("old").unwrap();
+        assert_eq!(notes.as_deref(), Some("new"));
+    }
+
+    #[test]
+    fn unit_new_notes_append() {
+        let notes = input(None, Some("more")).new_notes("old\n").unwrap();
+        assert_eq!(notes.as_deref(), Some("old\nmore"));
+        let notes = input(None, Some("more")).new_notes(" ").unwrap();
+        assert_eq!(notes.as_deref(), Some("more"));
+    }
+}
@@ -0,0 +1,77 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+use std::path::PathBuf;
+
+use super::{Cmd, Context};
+use crate::cli::cmd::download::preview_file_name;
+use crate::http::Error as HttpError;
+use crate::util::file;
+
+/// Download the preview image of an item.
+///
+/// The preview is an image of the first page of the first attachment.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The item id (can be abbreviated to a prefix)
+    pub id: String,
+
+    /// The file to write the image to. If not given, a file named
+    /// after the item's name is created in the current directory.
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Whether to overwrite an already existing file.
+    #[arg(long)]
+    pub overwrite: bool,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error creating a file. {}", source))]
+    CreateFile { source: std::io::Error },
+
+    #[snafu(display("File already exists: {}", path.display()))]
+    FileExists { path: PathBuf },
+
+    #[snafu(display("The item was not found: {}", id))]
+    ItemNotFound { id: String },
+
+    #[snafu(display("No preview available for item {}", id))]
+    NoPreview { id: String },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let item = ctx
+            .client
+            .get_item(&ctx.opts.session, &self.id)
+            .context(HttpClientSnafu)?
+            .ok_or_else(|| Error::ItemNotFound {
+                id: self.id.clone(),
+            })?;
+        let mut dl = ctx
+            .client
+            .download_item_preview(&ctx.opts.session, &item.id)
+            .context(HttpClientSnafu)?
+            .ok_or_else(|| Error::NoPreview {
+                id: item.id.clone(),
+            })?;
+        let path = match &self.output {
+            Some(p) => p.clone(),
+            None => PathBuf::from(preview_file_name(&file::safe_filename(&item.name), &dl)),
+        };
+        if path.exists() && !self.overwrite {
+            return Err(Error::FileExists { path });
+        }
+        let file = std::fs::File::create(&path).context(CreateFileSnafu)?;
+        let mut writer = std::io::BufWriter::new(file);
+        dl.copy_to(&mut writer).context(HttpClientSnafu)?;
+        println!("Downloaded preview to {}", path.display());
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+use clap::Parser;
+use dialoguer::Select;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::cli::table::format_date;
+use crate::http::payload::{BasicResult, IdName, ItemProposals, SearchMode};
+use crate::http::Error as HttpError;
+
+/// Show proposals for an item and optionally apply them.
+///
+/// Proposals are found by analysing the text of the attachments. With
+/// `--apply` the first proposal of each kind is set on the item. Use
+/// `--interactive` to choose a proposal of each kind instead.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The item id (can be abbreviated to a prefix)
+    pub id: String,
+
+    /// Set the proposals on the item.
+    #[arg(long)]
+    pub apply: bool,
+
+    /// Choose which proposals to apply.
+    #[arg(long, short, requires = "apply")]
+    pub interactive: bool,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+
+    #[snafu(display("Interaction with terminal failed: {}", source))]
+    Interact { source: dialoguer::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let id = ctx
+            .client
+            .require_item_id(&ctx.opts.session, &self.id, SearchMode::All)
+            .context(HttpClientSnafu)?;
+        let proposals = ctx
+            .client
+            .get_item_proposals(&ctx.opts.session, &id)
+            .context(HttpClientSnafu)?;
+        if self.apply {
+            let results = apply(self, &id, &proposals, ctx)?;
+            ctx.write_result(results).context(WriteResultSnafu)?;
+        } else {
+            ctx.write_result(proposals).context(WriteResultSnafu)?;
+        }
+        Ok(())
+    }
+}
+
+/// Applies the chosen proposals to the item. The `id` must be the
+/// complete item id.
+fn apply(
+    opts: &Input,
+    id: &str,
+    proposals: &ItemProposals,
+    ctx: &Context,
+) -> Result<Vec<BasicResult>, Error> {
+    let client = &ctx.client;
+    let token = &ctx.opts.session;
+    let mut results = Vec::new();
+
+    if let Some(org) = choose_id(opts, "Correspondent organization", &proposals.corr_org)? {
+        let r = client.set_item_corr_org(token, id, Some(org));
+        results.push(r.context(HttpClientSnafu)?);
+    }
+    if let Some(person) = choose_id(opts, "Correspondent person", &proposals.corr_person)? {
+        let r = client.set_item_corr_person(token, id, Some(person));
+        results.push(r.context(HttpClientSnafu)?);
+    }
+    if let Some(person) = choose_id(opts, "Concerning person", &proposals.conc_person)? {
+        let r = client.set_item_conc_person(token, id, Some(person));
+        results.push(r.context(HttpClientSnafu)?);
+    }
+    if let Some(equip) = choose_id(opts, "Concerning equipment", &proposals.conc_equipment)? {
+        let r = client.set_item_conc_equipment(token, id, Some(equip));
+        results.push(r.context(HttpClientSnafu)?);
+    }
+    if let Some(date) = choose_date(opts, "Date", &proposals.item_date)? {
+        let r = client.set_item_date(token, id, Some(date));
+        results.push(r.context(HttpClientSnafu)?);
+    }
+    if let Some(date) = choose_date(opts, "Due date", &proposals.due_date)? {
+        let r = client.set_item_due_date(token, id, Some(date));
+        results.push(r.context(HttpClientSnafu)?);
+    }
+    Ok(results)
+}
+
+fn choose_id<'a>(
+    opts: &Input,
+    prompt: &str,
+    proposals: &'a [IdName],
+) -> Result<Option<&'a str>, Error> {
+    let names: Vec<&str> = proposals.iter().map(|p| p.name.as_str()).collect();
+    let index = choose(opts, prompt, &names)?;
+    Ok(index.map(|i| proposals[i].id.as_str()))
+}
+
+fn choose_date(opts: &Input, prompt: &str, proposals: &[i64]) -> Result<Option<i64>, Error> {
+    let dates: Vec<String> = proposals.iter().map(|d| format_date(*d)).collect();
+    let index = choose(opts, prompt, &dates)?;
+    Ok(index.map(|i| proposals[i]))
+}
+
+/// Returns the index of the chosen proposal. This is the first one,
+/// unless running interactively. There the last choice allows to skip
+/// this kind of proposal.
+fn choose<T: ToString>(opts: &Input, prompt: &str, items: &[T]) -> Result<Option<usize>, Error> {
+    if items.is_empty() {
+        Ok(None)
+    } else if opts.interactive {
+        let mut choices: Vec<String> = items.iter().map(|i| i.to_string()).collect();
+        choices.push("(skip)".into());
+        let index = Select::new()
+            .with_prompt(prompt)
+            .items(&choices)
+            .default(0)
+            .interact_opt()
+            .context(InteractSnafu)?;
+        Ok(index.filter(|i| *i < items.len()))
+    } else {
+        Ok(Some(0))
+    }
+}
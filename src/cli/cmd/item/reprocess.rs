@@ -0,0 +1,94 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::opts::ItemSelect;
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::{BasicResult, SearchMode};
+use crate::http::Error as HttpError;
+
+/// Reprocess items.
+///
+/// This submits a job for each item that runs text extraction and
+/// analysis again. Metadata already set on the item is kept. Items
+/// selected by a query are submitted with a single request.
+#[derive(Parser, Debug)]
+pub struct Input {
+    #[clap(flatten)]
+    pub items: ItemSelect,
+
+    /// Only reprocess these attachments, given by their id (can be
+    /// abbreviated to a prefix) or name. This requires a single item.
+    #[arg(long = "attachment", conflicts_with = "query")]
+    pub attachments: Vec<String>,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+
+    #[snafu(display("Attachments can only be given for a single item"))]
+    SingleItemRequired,
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        if self.items.query.is_some() {
+            return self.reprocess_all(ctx);
+        }
+        let attachment_ids = self.attachment_ids(ctx)?;
+        let results = super::for_each_item(&self.items, SearchMode::Normal, ctx, |id| {
+            ctx.client
+                .reprocess_item(&ctx.opts.session, id, &attachment_ids)
+        })
+        .context(HttpClientSnafu)?;
+        ctx.write_result(results).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
+
+impl Input {
+    fn reprocess_all(&self, ctx: &Context) -> Result<(), Error> {
+        let ids = self
+            .items
+            .resolve_ids(&ctx.client, &ctx.opts.session, SearchMode::Normal)
+            .context(HttpClientSnafu)?;
+        let result = if ids.is_empty() {
+            BasicResult {
+                success: true,
+                message: "No items found.".into(),
+            }
+        } else {
+            ctx.client
+                .reprocess_items(&ctx.opts.session, &ids)
+                .context(HttpClientSnafu)?
+        };
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+
+    fn attachment_ids(&self, ctx: &Context) -> Result<Vec<String>, Error> {
+        if self.attachments.is_empty() {
+            return Ok(vec![]);
+        }
+        match self.items.ids.as_slice() {
+            [item_id] => self
+                .attachments
+                .iter()
+                .map(|a| {
+                    ctx.client
+                        .find_attachment(&ctx.opts.session, item_id, a)
+                        .map(|a| a.id)
+                        .context(HttpClientSnafu)
+                })
+                .collect(),
+            _ => Err(Error::SingleItemRequired),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::opts::ItemSelect;
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::SearchMode;
+use crate::http::Error as HttpError;
+
+/// Restore items from the trash.
+#[derive(Parser, Debug)]
+pub struct Input {
+    #[clap(flatten)]
+    pub items: ItemSelect,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let results = super::for_each_item(&self.items, SearchMode::Trashed, ctx, |id| {
+            ctx.client.restore_item(&ctx.opts.session, id)
+        })
+        .context(HttpClientSnafu)?;
+        ctx.write_result(results).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,194 @@
+use clap::{ArgGroup, Parser, ValueHint};
+use dialoguer::Confirm;
+use snafu::{ResultExt, Snafu};
+use std::path::PathBuf;
+
+use super::{Cmd, Context};
+use crate::cli::opts::ItemSelect;
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::{BasicResult, SearchMode, SimpleMail};
+use crate::http::Error as HttpError;
+
+/// Send items by e-mail.
+///
+/// The mails are sent by the server using one of your SMTP
+/// connections (see `mail-settings smtp`). With `--query`, one mail
+/// is sent for each matching item after showing the number of items
+/// and asking for confirmation. A result is printed for each item.
+/// Use `item sent-mails` to see the mails sent for an item.
+#[derive(Parser, Debug)]
+#[command(group = ArgGroup::new("body"))]
+pub struct Input {
+    #[clap(flatten)]
+    pub items: ItemSelect,
+
+    /// The recipients of the mail.
+    #[arg(long = "to", required = true)]
+    pub recipients: Vec<String>,
+
+    /// Recipients that receive a copy.
+    #[arg(long)]
+    pub cc: Vec<String>,
+
+    /// Recipients that receive a blind copy.
+    #[arg(long)]
+    pub bcc: Vec<String>,
+
+    /// The subject of the mail.
+    #[arg(long)]
+    pub subject: String,
+
+    /// The text of the mail.
+    #[arg(long, group = "body")]
+    pub body: Option<String>,
+
+    /// Read the text of the mail from this file.
+    #[arg(long, group = "body", value_hint = ValueHint::FilePath)]
+    pub body_file: Option<PathBuf>,
+
+    /// The name of the SMTP connection to use. Can be omitted, if
+    /// there is only one.
+    #[arg(long)]
+    pub smtp: Option<String>,
+
+    /// Attach all files of the item to the mail.
+    #[arg(long)]
+    pub attach_all: bool,
+
+    /// Don't ask for confirmation when using `--query`.
+    #[arg(long, short)]
+    pub yes: bool,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+
+    #[snafu(display("Error reading file {}: {}", path.display(), source))]
+    ReadBody {
+        source: std::io::Error,
+        path: PathBuf,
+    },
+
+    #[snafu(display("No SMTP connection found, add one via `mail-settings smtp add`"))]
+    NoSmtpConnection,
+
+    #[snafu(display(
+        "There are multiple SMTP connections, choose one via --smtp: {}",
+        names
+    ))]
+    MultipleSmtpConnections { names: String },
+
+    #[snafu(display("Interaction with terminal failed: {}", source))]
+    Interact { source: dialoguer::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let connection = smtp_connection(self, ctx)?;
+        let body = match &self.body_file {
+            Some(path) => std::fs::read_to_string(path).context(ReadBodySnafu { path })?,
+            None => self.body.clone().unwrap_or_default(),
+        };
+        let mail = SimpleMail {
+            recipients: self.recipients.clone(),
+            cc: self.cc.clone(),
+            bcc: self.bcc.clone(),
+            subject: self.subject.clone(),
+            body,
+            add_all_attachments: self.attach_all,
+            attachment_ids: vec![],
+        };
+        let ids = self.item_ids(ctx)?;
+        let ask = self.items.query.is_some() && !ids.is_empty() && !self.yes;
+        if ask && !confirm(&ids)? {
+            let result = BasicResult {
+                success: false,
+                message: "Sending cancelled.".into(),
+            };
+            ctx.write_result(result).context(WriteResultSnafu)?;
+            return Ok(());
+        }
+        let results: Vec<BasicResult> = ids
+            .iter()
+            .map(|id| {
+                ctx.client
+                    .send_item_mail(&ctx.opts.session, &connection, id, &mail)
+                    .unwrap_or_else(|err| BasicResult {
+                        success: false,
+                        message: format!("Sending mail for item {} failed: {}", id, err),
+                    })
+            })
+            .collect();
+        ctx.write_result(results).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
+
+impl Input {
+    /// Returns the complete ids of the selected items.
+    fn item_ids(&self, ctx: &Context) -> Result<Vec<String>, Error> {
+        let token = &ctx.opts.session;
+        match &self.items.query {
+            Some(_) => self
+                .items
+                .resolve_ids(&ctx.client, token, SearchMode::Normal)
+                .context(HttpClientSnafu),
+            None => self
+                .items
+                .ids
+                .iter()
+                .map(|id| {
+                    ctx.client
+                        .require_item_id(token, id, SearchMode::All)
+                        .context(HttpClientSnafu)
+                })
+                .collect(),
+        }
+    }
+}
+
+fn confirm(ids: &[String]) -> Result<bool, Error> {
+    eprintln!("The query matches {} item(s):", ids.len());
+    for id in ids {
+        eprintln!("  {}", id);
+    }
+    let answer = Confirm::new()
+        .with_prompt("Send a mail for each of these items?")
+        .default(false)
+        .interact_opt()
+        .context(InteractSnafu)?;
+    Ok(answer.unwrap_or(false))
+}
+
+/// Returns the given SMTP connection name or the name of the only
+/// existing connection.
+fn smtp_connection(opts: &Input, ctx: &Context) -> Result<String, Error> {
+    match &opts.smtp {
+        Some(name) => Ok(name.clone()),
+        None => {
+            let mut settings = ctx
+                .client
+                .list_smtp_settings(&ctx.opts.session, "")
+                .context(HttpClientSnafu)?
+                .items;
+            match settings.len() {
+                0 => Err(Error::NoSmtpConnection),
+                1 => Ok(settings.remove(0).name),
+                _ => Err(Error::MultipleSmtpConnections {
+                    names: settings
+                        .iter()
+                        .map(|s| s.name.as_str())
+                        .collect::<Vec<&str>>()
+                        .join(", "),
+                }),
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::SearchMode;
+use crate::http::Error as HttpError;
+
+/// Show the mails that have been sent for an item.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The item id (can be abbreviated to a prefix)
+    pub id: String,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let id = ctx
+            .client
+            .require_item_id(&ctx.opts.session, &self.id, SearchMode::All)
+            .context(HttpClientSnafu)?;
+        let mails = ctx
+            .client
+            .get_sent_mails(&ctx.opts.session, &id)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(mails).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+use clap::{ArgGroup, Parser, ValueEnum};
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::opts::{parse_date, Direction};
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::BasicResult;
+use crate::http::Error as HttpError;
+
+/// Change metadata of an item.
+///
+/// Organizations, persons, equipments and folders can be given by
+/// their id (can be abbreviated to a prefix) or name. Dates are given
+/// as `YYYY-MM-DD`. Multiple properties can be changed at once.
+#[derive(Parser, Debug)]
+#[command(group = ArgGroup::new("property").required(true).multiple(true))]
+pub struct Input {
+    /// The item id (can be abbreviated to a prefix)
+    #[arg(long)]
+    pub id: String,
+
+    /// Set the name of the item.
+    #[arg(long, group = "property")]
+    pub name: Option<String>,
+
+    /// Set the date of the item.
+    #[arg(long, group = "property", value_parser = parse_date)]
+    pub date: Option<i64>,
+
+    /// Set the due date of the item.
+    #[arg(long, group = "property", value_parser = parse_date)]
+    pub due_date: Option<i64>,
+
+    /// Set the direction of the item.
+    #[arg(long, group = "property", value_enum)]
+    pub direction: Option<Direction>,
+
+    /// Set the correspondent organization.
+    #[arg(long, group = "property")]
+    pub corr_org: Option<String>,
+
+    /// Set the correspondent person.
+    #[arg(long, group = "property")]
+    pub corr_person: Option<String>,
+
+    /// Set the concerning person.
+    #[arg(long, group = "property")]
+    pub conc_person: Option<String>,
+
+    /// Set the concerning equipment.
+    #[arg(long, group = "property")]
+    pub conc_equipment: Option<String>,
+
+    /// Move the item into this folder.
+    #[arg(long, group = "property")]
+    pub folder: Option<String>,
+
+    /// Remove the given property from the item. Can be given multiple
+    /// times.
+    #[arg(long, group = "property", value_enum)]
+    pub clear: Vec<Property>,
+}
+
+/// The item properties that can be removed.
+#[derive(ValueEnum, Debug, Clone, PartialEq)]
+pub enum Property {
+    Date,
+    DueDate,
+    CorrOrg,
+    CorrPerson,
+    ConcPerson,
+    ConcEquipment,
+    Folder,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+
+    #[snafu(display("The item was not found"))]
+    ItemNotFound,
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let item = ctx
+            .client
+            .get_item(&ctx.opts.session, &self.id)
+            .context(HttpClientSnafu)?
+            .ok_or(Error::ItemNotFound)?;
+        let results = set_properties(self, &item.id, ctx).context(HttpClientSnafu)?;
+        ctx.write_result(results).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
+
+fn set_properties(opts: &Input, id: &str, ctx: &Context) -> Result<Vec<BasicResult>, HttpError> {
+    let client = &ctx.client;
+    let token = &ctx.opts.session;
+    let mut results = Vec::new();
+
+    if let Some(name) = &opts.name {
+        results.push(client.set_item_name(token, id, name)?);
+    }
+    if opts.date.is_some() {
+        results.push(client.set_item_date(token, id, opts.date)?);
+    }
+    if opts.due_date.is_some() {
+        results.push(client.set_item_due_date(token, id, opts.due_date)?);
+    }
+    if let Some(dir) = &opts.direction {
+        results.push(client.set_item_direction(token, id, dir.to_value())?);
+    }
+    if let Some(org) = &opts.corr_org {
+        let org = client.find_organization(token, org)?;
+        results.push(client.set_item_corr_org(token, id, Some(org.id.as_str()))?);
+    }
+    if let Some(person) = &opts.corr_person {
+        let person = client.find_person(token, person)?;
+        results.push(client.set_item_corr_person(token, id, Some(person.id.as_str()))?);
+    }
+    if let Some(person) = &opts.conc_person {
+        let person = client.find_person(token, person)?;
+        results.push(client.set_item_conc_person(token, id, Some(person.id.as_str()))?);
+    }
+    if let Some(equip) = &opts.conc_equipment {
+        let equip = client.find_equipment(token, equip)?;
+        results.push(client.set_item_conc_equipment(token, id, Some(equip.id.as_str()))?);
+    }
+    if let Some(folder) = &opts.folder {
+        let folder = client.find_folder(token, folder)?;
+        results.push(client.set_item_folder(token, id, Some(folder.id.as_str()))?);
+    }
+
+    for prop in &opts.clear {
+        let result = match prop {
+            Property::Date => client.set_item_date(token, id, None)?,
+            Property::DueDate => client.set_item_due_date(token, id, None)?,
+            Property::CorrOrg => client.set_item_corr_org(token, id, None)?,
+            Property::CorrPerson => client.set_item_corr_person(token, id, None)?,
+            Property::ConcPerson => client.set_item_conc_person(token, id, None)?,
+            Property::ConcEquipment => client.set_item_conc_equipment(token, id, None)?,
+            Property::Folder => client.set_item_folder(token, id, None)?,
+        };
+        results.push(result);
+    }
+    Ok(results)
+}
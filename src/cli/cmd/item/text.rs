@@ -0,0 +1,65 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::opts::Format;
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::AttachmentText;
+use crate::http::Error as HttpError;
+
+/// Show the extracted text of an item.
+///
+/// In tabular format, the text of all attachments is printed one after
+/// another. Other formats print the text per attachment.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The item id (can be abbreviated to a prefix)
+    pub id: String,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+
+    #[snafu(display("The item was not found"))]
+    ItemNotFound,
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let item = ctx
+            .client
+            .get_item(&ctx.opts.session, &self.id)
+            .context(HttpClientSnafu)?
+            .ok_or(Error::ItemNotFound)?;
+        let texts = item
+            .attachments
+            .into_iter()
+            .map(|a| {
+                ctx.client
+                    .get_attachment_meta(&ctx.opts.session, &a.id)
+                    .map(|meta| AttachmentText {
+                        id: a.id,
+                        name: a.name,
+                        content: meta.content,
+                    })
+                    .context(HttpClientSnafu)
+            })
+            .collect::<Result<Vec<AttachmentText>, Error>>()?;
+        match ctx.format() {
+            Format::Tabular => {
+                for text in texts {
+                    println!("{}", text.content);
+                }
+            }
+            _ => ctx.write_result(texts).context(WriteResultSnafu)?,
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+pub mod imap;
+pub mod smtp;
+
+use clap::{Parser, ValueEnum};
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::util::pass;
+
+/// Manage e-mail connections.
+///
+/// IMAP connections are used to import mails via `scan-mailbox`,
+/// SMTP connections are used for sending mails.
+#[derive(Parser, std::fmt::Debug)]
+pub struct Input {
+    #[command(subcommand)]
+    pub subcmd: MailSettingsCommand,
+}
+
+#[derive(Parser, Debug)]
+pub enum MailSettingsCommand {
+    #[command(version)]
+    Imap(imap::Input),
+
+    #[command(version)]
+    Smtp(smtp::Input),
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    Imap { source: imap::Error },
+    Smtp { source: smtp::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        match &self.subcmd {
+            MailSettingsCommand::Imap(input) => input.exec(ctx).context(ImapSnafu),
+            MailSettingsCommand::Smtp(input) => input.exec(ctx).context(SmtpSnafu),
+        }
+    }
+}
+
+/// How to secure the connection to the mail server.
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum SslType {
+    None,
+    Starttls,
+    Ssl,
+}
+impl SslType {
+    pub fn to_value(&self) -> &'static str {
+        match self {
+            SslType::None => "none",
+            SslType::Starttls => "starttls",
+            SslType::Ssl => "ssl",
+        }
+    }
+}
+
+/// Gets the password from the pass entry, if given, or uses the
+/// plain password.
+fn get_password(
+    password: &Option<String>,
+    pass_entry: &Option<String>,
+) -> Result<Option<String>, std::io::Error> {
+    match pass_entry {
+        Some(pe) => pass::pass_password(pe).map(Some),
+        None => Ok(password.clone()),
+    }
+}
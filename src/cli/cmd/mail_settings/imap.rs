@@ -0,0 +1,46 @@
+pub mod add;
+pub mod delete;
+pub mod list;
+
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+
+/// Manage IMAP connections.
+#[derive(Parser, std::fmt::Debug)]
+pub struct Input {
+    #[command(subcommand)]
+    pub subcmd: ImapCommand,
+}
+
+#[derive(Parser, Debug)]
+pub enum ImapCommand {
+    #[command(version)]
+    List(list::Input),
+
+    #[command(version)]
+    Add(add::Input),
+
+    #[command(version)]
+    Delete(delete::Input),
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    List { source: list::Error },
+    Add { source: add::Error },
+    Delete { source: delete::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        match &self.subcmd {
+            ImapCommand::List(input) => input.exec(ctx).context(ListSnafu),
+            ImapCommand::Add(input) => input.exec(ctx).context(AddSnafu),
+            ImapCommand::Delete(input) => input.exec(ctx).context(DeleteSnafu),
+        }
+    }
+}
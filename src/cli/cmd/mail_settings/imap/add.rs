@@ -0,0 +1,88 @@
+use clap::{ArgGroup, Parser};
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::cmd::mail_settings::{get_password, SslType};
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::ImapSettings;
+use crate::http::Error as HttpError;
+
+/// Create a new IMAP connection for importing mails.
+#[derive(Parser, Debug)]
+#[command(group = ArgGroup::new("pass"))]
+pub struct Input {
+    /// The name of the connection.
+    #[arg(long)]
+    pub name: String,
+
+    /// The host name of the IMAP server.
+    #[arg(long)]
+    pub host: String,
+
+    /// The port of the IMAP server. If not given, the default port
+    /// for the ssl type is used.
+    #[arg(long)]
+    pub port: Option<u16>,
+
+    /// The user for logging into the IMAP server.
+    #[arg(long)]
+    pub user: Option<String>,
+
+    /// The password in plain text. When using OAuth, this is the
+    /// access token.
+    #[arg(long, group = "pass")]
+    pub password: Option<String>,
+
+    /// An entry for the pass password manager containing the
+    /// password.
+    #[arg(long, group = "pass")]
+    pub pass_entry: Option<String>,
+
+    /// How to secure the connection.
+    #[arg(long, value_enum, default_value = "ssl")]
+    pub ssl: SslType,
+
+    /// Don't verify the certificate of the server.
+    #[arg(long)]
+    pub ignore_certificates: bool,
+
+    /// Authenticate via OAuth, using the password as access token.
+    #[arg(long)]
+    pub use_oauth: bool,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+
+    #[snafu(display("Retrieving password using pass failed: {}", source))]
+    PassEntry { source: std::io::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let password = get_password(&self.password, &self.pass_entry).context(PassEntrySnafu)?;
+        let settings = ImapSettings {
+            name: self.name.clone(),
+            imap_host: self.host.clone(),
+            imap_port: self.port,
+            imap_user: self.user.clone(),
+            imap_password: password,
+            ssl_type: self.ssl.to_value().into(),
+            ignore_certificates: self.ignore_certificates,
+            use_oauth: self.use_oauth,
+        };
+        let result = ctx
+            .client
+            .create_imap_settings(&ctx.opts.session, &settings)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
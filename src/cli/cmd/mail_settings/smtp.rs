@@ -0,0 +1,46 @@
+pub mod add;
+pub mod delete;
+pub mod list;
+
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+
+/// Manage SMTP connections.
+#[derive(Parser, std::fmt::Debug)]
+pub struct Input {
+    #[command(subcommand)]
+    pub subcmd: SmtpCommand,
+}
+
+#[derive(Parser, Debug)]
+pub enum SmtpCommand {
+    #[command(version)]
+    List(list::Input),
+
+    #[command(version)]
+    Add(add::Input),
+
+    #[command(version)]
+    Delete(delete::Input),
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    List { source: list::Error },
+    Add { source: add::Error },
+    Delete { source: delete::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        match &self.subcmd {
+            SmtpCommand::List(input) => input.exec(ctx).context(ListSnafu),
+            SmtpCommand::Add(input) => input.exec(ctx).context(AddSnafu),
+            SmtpCommand::Delete(input) => input.exec(ctx).context(DeleteSnafu),
+        }
+    }
+}
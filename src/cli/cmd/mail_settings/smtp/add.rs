@@ -0,0 +1,92 @@
+use clap::{ArgGroup, Parser};
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::cmd::mail_settings::{get_password, SslType};
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::EmailSettings;
+use crate::http::Error as HttpError;
+
+/// Create a new SMTP connection for sending mails.
+#[derive(Parser, Debug)]
+#[command(group = ArgGroup::new("pass"))]
+pub struct Input {
+    /// The name of the connection.
+    #[arg(long)]
+    pub name: String,
+
+    /// The host name of the SMTP server.
+    #[arg(long)]
+    pub host: String,
+
+    /// The port of the SMTP server. If not given, the default port
+    /// for the ssl type is used.
+    #[arg(long)]
+    pub port: Option<u16>,
+
+    /// The user for logging into the SMTP server.
+    #[arg(long)]
+    pub user: Option<String>,
+
+    /// The password in plain text.
+    #[arg(long, group = "pass")]
+    pub password: Option<String>,
+
+    /// An entry for the pass password manager containing the
+    /// password.
+    #[arg(long, group = "pass")]
+    pub pass_entry: Option<String>,
+
+    /// The sender address of the mails.
+    #[arg(long)]
+    pub from: String,
+
+    /// An optional reply-to address.
+    #[arg(long)]
+    pub reply_to: Option<String>,
+
+    /// How to secure the connection.
+    #[arg(long, value_enum, default_value = "starttls")]
+    pub ssl: SslType,
+
+    /// Don't verify the certificate of the server.
+    #[arg(long)]
+    pub ignore_certificates: bool,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+
+    #[snafu(display("Retrieving password using pass failed: {}", source))]
+    PassEntry { source: std::io::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let password = get_password(&self.password, &self.pass_entry).context(PassEntrySnafu)?;
+        let settings = EmailSettings {
+            name: self.name.clone(),
+            smtp_host: self.host.clone(),
+            smtp_port: self.port,
+            smtp_user: self.user.clone(),
+            smtp_password: password,
+            from: self.from.clone(),
+            reply_to: self.reply_to.clone(),
+            ssl_type: self.ssl.to_value().into(),
+            ignore_certificates: self.ignore_certificates,
+        };
+        let result = ctx
+            .client
+            .create_smtp_settings(&ctx.opts.session, &settings)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::Error as HttpError;
+
+/// Lists SMTP connections.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// Only list connections whose name contains the given text.
+    #[arg(long, default_value = "")]
+    pub name: String,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let mut settings = ctx
+            .client
+            .list_smtp_settings(&ctx.opts.session, &self.name)
+            .context(HttpClientSnafu)?;
+        // don't print stored credentials
+        for s in settings.items.iter_mut() {
+            s.smtp_password = None;
+        }
+        ctx.write_result(settings).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+pub mod channel;
+pub mod hook;
+
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+
+/// Manage notifications.
+///
+/// Channels define where notifications are sent to, like a Matrix
+/// room or a Gotify server. Hooks define which events are sent to
+/// which channels.
+#[derive(Parser, std::fmt::Debug)]
+pub struct Input {
+    #[command(subcommand)]
+    pub subcmd: NotifyCommand,
+}
+
+#[derive(Parser, Debug)]
+pub enum NotifyCommand {
+    #[command(version)]
+    Channel(channel::Input),
+
+    #[command(version)]
+    Hook(hook::Input),
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    Channel { source: channel::Error },
+    Hook { source: hook::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        match &self.subcmd {
+            NotifyCommand::Channel(input) => input.exec(ctx).context(ChannelSnafu),
+            NotifyCommand::Hook(input) => input.exec(ctx).context(HookSnafu),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+pub mod add;
+pub mod delete;
+pub mod list;
+
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::http::payload::ChannelRef;
+use crate::http::Error as HttpError;
+
+/// Manage notification channels.
+#[derive(Parser, std::fmt::Debug)]
+pub struct Input {
+    #[command(subcommand)]
+    pub subcmd: ChannelCommand,
+}
+
+#[derive(Parser, Debug)]
+pub enum ChannelCommand {
+    #[command(version)]
+    List(list::Input),
+
+    #[command(version)]
+    Add(add::Input),
+
+    #[command(version)]
+    Delete(delete::Input),
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    List { source: list::Error },
+    Add { source: add::Error },
+    Delete { source: delete::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        match &self.subcmd {
+            ChannelCommand::List(input) => input.exec(ctx).context(ListSnafu),
+            ChannelCommand::Add(input) => input.exec(ctx).context(AddSnafu),
+            ChannelCommand::Delete(input) => input.exec(ctx).context(DeleteSnafu),
+        }
+    }
+}
+
+/// Resolves the given channels, given by their id (can be abbreviated
+/// to a prefix) or name, to the references used by hooks.
+pub fn resolve_channels(channels: &[String], ctx: &Context) -> Result<Vec<ChannelRef>, HttpError> {
+    let mut refs = Vec::with_capacity(channels.len());
+    for channel in channels {
+        let found = ctx.client.find_channel(&ctx.opts.session, channel)?;
+        refs.push(found.to_ref());
+    }
+    Ok(refs)
+}
@@ -0,0 +1,372 @@
+use clap::{Parser, ValueHint};
+use serde::{Deserialize, Serialize};
+use snafu::{ResultExt, Snafu};
+use std::path::{Path, PathBuf};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::{
+    BasicResult, NotificationChannel, NotificationGotify, NotificationHttp, NotificationMail,
+    NotificationMatrix,
+};
+use crate::http::Error as HttpError;
+
+/// Create notification channels.
+///
+/// Either give the channel via one of the subcommands or load channel
+/// definitions from a TOML file via `--file`. The file contains a
+/// list of `[[channel]]` tables, each with a `channelType` (one of
+/// mail, gotify, matrix, http) and the properties of that type as in
+/// the JSON output of `notify channel list`, for example `name`,
+/// `url` and `appKey` for gotify. Ntfy channels are given as a list
+/// of `[[ntfy]]` tables with the options of the `ntfy` subcommand.
+/// Each channel is created separately and a result is printed for
+/// each.
+#[derive(Parser, Debug)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct Input {
+    /// Load channel definitions from this TOML file.
+    #[arg(long, value_hint = ValueHint::FilePath)]
+    pub file: Option<PathBuf>,
+
+    #[command(subcommand)]
+    pub channel: Option<ChannelType>,
+}
+
+#[derive(Parser, Debug)]
+pub enum ChannelType {
+    /// Send notifications as e-mails via a SMTP connection.
+    Mail(MailArgs),
+
+    /// Send notifications to a Gotify server.
+    Gotify(GotifyArgs),
+
+    /// Send notifications to a Matrix room.
+    Matrix(MatrixArgs),
+
+    /// Post notifications as JSON to some url.
+    Http(HttpArgs),
+
+    /// Publish notifications to a ntfy topic.
+    ///
+    /// Docspell has no ntfy channel type, so this creates a http
+    /// channel. Its url enables ntfy's message templates, which take
+    /// the title and text of the notification from the posted JSON.
+    Ntfy(NtfyArgs),
+}
+
+#[derive(Parser, Debug)]
+pub struct MailArgs {
+    /// An optional name for the channel.
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// The name of the SMTP connection to use.
+    #[arg(long)]
+    pub connection: String,
+
+    /// The recipients of the mails.
+    #[arg(long = "recipient", required = true)]
+    pub recipients: Vec<String>,
+}
+
+#[derive(Parser, Debug)]
+pub struct GotifyArgs {
+    /// An optional name for the channel.
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// The url of the Gotify server.
+    #[arg(long)]
+    pub url: String,
+
+    /// The application token.
+    #[arg(long)]
+    pub app_key: String,
+
+    /// The priority of the messages.
+    #[arg(long)]
+    pub priority: Option<i32>,
+}
+
+#[derive(Parser, Debug)]
+pub struct MatrixArgs {
+    /// An optional name for the channel.
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// The url of the home server.
+    #[arg(long)]
+    pub home_server: String,
+
+    /// The id of the room to send messages to.
+    #[arg(long)]
+    pub room_id: String,
+
+    /// The access token of the user sending the messages.
+    #[arg(long)]
+    pub access_token: String,
+}
+
+#[derive(Parser, Debug)]
+pub struct HttpArgs {
+    /// An optional name for the channel.
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// The url to post notifications to.
+    #[arg(long)]
+    pub url: String,
+}
+
+#[derive(Parser, Debug, Serialize, Deserialize)]
+pub struct NtfyArgs {
+    /// An optional name for the channel.
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// The url of the topic, like `https://ntfy.sh/mytopic`.
+    #[arg(long)]
+    pub url: String,
+
+    /// The priority of the messages, from 1 (min) to 5 (max).
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=5))]
+    pub priority: Option<u8>,
+
+    /// Tags to add to the messages, which can also be emoji short
+    /// codes.
+    #[arg(long = "tag")]
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// The contents of a TOML file with channel definitions.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelFile {
+    #[serde(default)]
+    pub channel: Vec<NotificationChannel>,
+
+    #[serde(default)]
+    pub ntfy: Vec<NtfyArgs>,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+
+    #[snafu(display("Error reading file {}: {}", path.display(), source))]
+    ReadFile {
+        source: std::io::Error,
+        path: PathBuf,
+    },
+
+    #[snafu(display("Error parsing file {}: {}", path.display(), source))]
+    ParseFile {
+        source: Box<toml::de::Error>,
+        path: PathBuf,
+    },
+
+    #[snafu(display("Either a channel type or --file must be given"))]
+    NoChannel,
+
+    #[snafu(display("Invalid url '{}': {}", url, message))]
+    InvalidUrl { url: String, message: String },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let channels = match (&self.file, &self.channel) {
+            (Some(file), _) => load_file(file)?,
+            (None, Some(args)) => vec![args.to_channel()?],
+            (None, None) => return Err(Error::NoChannel),
+        };
+        let results: Vec<BasicResult> = channels
+            .iter()
+            .map(|channel| create(channel, ctx))
+            .collect();
+        ctx.write_result(results).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
+
+impl ChannelType {
+    fn to_channel(&self) -> Result<NotificationChannel, Error> {
+        let channel = match self {
+            ChannelType::Mail(args) => NotificationChannel::Mail(NotificationMail {
+                id: "".into(),
+                name: args.name.clone(),
+                connection: args.connection.clone(),
+                recipients: args.recipients.clone(),
+            }),
+            ChannelType::Gotify(args) => NotificationChannel::Gotify(NotificationGotify {
+                id: "".into(),
+                name: args.name.clone(),
+                url: args.url.clone(),
+                app_key: args.app_key.clone(),
+                priority: args.priority,
+            }),
+            ChannelType::Matrix(args) => NotificationChannel::Matrix(NotificationMatrix {
+                id: "".into(),
+                name: args.name.clone(),
+                home_server: args.home_server.clone(),
+                room_id: args.room_id.clone(),
+                access_token: args.access_token.clone(),
+            }),
+            ChannelType::Http(args) => NotificationChannel::Http(NotificationHttp {
+                id: "".into(),
+                name: args.name.clone(),
+                url: args.url.clone(),
+            }),
+            ChannelType::Ntfy(args) => args.to_channel()?,
+        };
+        Ok(channel)
+    }
+}
+
+impl NtfyArgs {
+    /// Creates a http channel posting to the topic url. The query
+    /// parameters let ntfy render the title and body of the message
+    /// that docspell adds to the posted JSON.
+    fn to_channel(&self) -> Result<NotificationChannel, Error> {
+        let mut url = reqwest::Url::parse(&self.url).map_err(|e| Error::InvalidUrl {
+            url: self.url.clone(),
+            message: e.to_string(),
+        })?;
+        {
+            let mut query = url.query_pairs_mut();
+            query
+                .append_pair("tpl", "yes")
+                .append_pair("t", "{{.message.title}}")
+                .append_pair("m", "{{.message.body}}");
+            if let Some(prio) = self.priority {
+                query.append_pair("p", &prio.to_string());
+            }
+            if !self.tags.is_empty() {
+                query.append_pair("ta", &self.tags.join(","));
+            }
+        }
+        Ok(NotificationChannel::Http(NotificationHttp {
+            id: "".into(),
+            name: self.name.clone(),
+            url: url.to_string(),
+        }))
+    }
+}
+
+/// Creates the channel, turning a failure into an unsuccessful result
+/// so that the remaining channels are still created.
+fn create(channel: &NotificationChannel, ctx: &Context) -> BasicResult {
+    match ctx.client.create_channel(&ctx.opts.session, channel) {
+        Ok(result) => result,
+        Err(err) => BasicResult {
+            success: false,
+            message: format!(
+                "Creating {} channel '{}' failed: {}",
+                channel.channel_type(),
+                channel.name().map(String::as_str).unwrap_or("<unnamed>"),
+                err
+            ),
+        },
+    }
+}
+
+/// Reads channel definitions from the given TOML file.
+pub fn load_file(file: &Path) -> Result<Vec<NotificationChannel>, Error> {
+    let content = std::fs::read_to_string(file).context(ReadFileSnafu { path: file })?;
+    let parsed: ChannelFile = toml::from_str(&content)
+        .map_err(Box::new)
+        .context(ParseFileSnafu { path: file })?;
+    let mut channels = parsed.channel;
+    for ntfy in &parsed.ntfy {
+        channels.push(ntfy.to_channel()?);
+    }
+    Ok(channels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_load_file() {
+        let file = std::env::temp_dir().join(format!("dsc-channels-{}.toml", std::process::id()));
+        std::fs::write(
+            &file,
+            r#"
+[[channel]]
+channelType = "gotify"
+name = "phone"
+url = "https://gotify.example.com"
+appKey = "secret"
+priority = 5
+
+[[channel]]
+channelType = "http"
+url = "https://example.com/hook"
+
+[[ntfy]]
+name = "ntfy"
+url = "https://ntfy.sh/mytopic"
+"#,
+        )
+        .unwrap();
+        let channels = load_file(&file);
+        std::fs::remove_file(&file).unwrap();
+
+        let channels = channels.unwrap();
+        assert_eq!(channels.len(), 3);
+        match &channels[0] {
+            NotificationChannel::Gotify(c) => {
+                assert_eq!(c.name.as_deref(), Some("phone"));
+                assert_eq!(c.app_key, "secret");
+                assert_eq!(c.priority, Some(5));
+            }
+            other => panic!("Unexpected channel: {:?}", other),
+        }
+        assert_eq!(channels[1].channel_type(), "http");
+        assert_eq!(channels[1].name(), None);
+        assert_eq!(channels[2].channel_type(), "http");
+        assert_eq!(channels[2].name().map(String::as_str), Some("ntfy"));
+    }
+
+    #[test]
+    fn unit_ntfy_channel() {
+        let args = NtfyArgs {
+            name: None,
+            url: "https://ntfy.sh/mytopic".into(),
+            priority: Some(4),
+            tags: vec!["warning".into(), "docs".into()],
+        };
+        match args.to_channel().unwrap() {
+            NotificationChannel::Http(c) => assert_eq!(
+                c.url,
+                "https://ntfy.sh/mytopic?tpl=yes&t=%7B%7B.message.title%7D%7D\
+                 &m=%7B%7B.message.body%7D%7D&p=4&ta=warning%2Cdocs"
+            ),
+            other => panic!("Unexpected channel: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unit_ntfy_channel_invalid_url() {
+        let args = NtfyArgs {
+            name: None,
+            url: "ntfy.sh/mytopic".into(),
+            priority: None,
+            tags: vec![],
+        };
+        assert!(matches!(args.to_channel(), Err(Error::InvalidUrl { .. })));
+    }
+
+    #[test]
+    fn unit_load_file_missing() {
+        let result = load_file(Path::new("/non/existing/channels.toml"));
+        assert!(matches!(result, Err(Error::ReadFile { .. })));
+    }
+}
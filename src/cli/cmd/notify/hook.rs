@@ -0,0 +1,73 @@
+pub mod add;
+pub mod delete;
+pub mod list;
+pub mod test;
+
+use clap::{Parser, ValueEnum};
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+
+/// Manage notification hooks.
+#[derive(Parser, std::fmt::Debug)]
+pub struct Input {
+    #[command(subcommand)]
+    pub subcmd: HookCommand,
+}
+
+#[derive(Parser, Debug)]
+pub enum HookCommand {
+    #[command(version)]
+    List(list::Input),
+
+    #[command(version)]
+    Add(add::Input),
+
+    #[command(version)]
+    Delete(delete::Input),
+
+    #[command(version)]
+    Test(test::Input),
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    List { source: list::Error },
+    Add { source: add::Error },
+    Delete { source: delete::Error },
+    Test { source: test::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        match &self.subcmd {
+            HookCommand::List(input) => input.exec(ctx).context(ListSnafu),
+            HookCommand::Add(input) => input.exec(ctx).context(AddSnafu),
+            HookCommand::Delete(input) => input.exec(ctx).context(DeleteSnafu),
+            HookCommand::Test(input) => input.exec(ctx).context(TestSnafu),
+        }
+    }
+}
+
+/// The events that can trigger a notification.
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum EventType {
+    TagsChanged,
+    SetFieldValue,
+    DeleteFieldValue,
+    JobSubmitted,
+    JobDone,
+}
+impl EventType {
+    pub fn to_value(&self) -> &'static str {
+        match self {
+            EventType::TagsChanged => "TagsChanged",
+            EventType::SetFieldValue => "SetFieldValue",
+            EventType::DeleteFieldValue => "DeleteFieldValue",
+            EventType::JobSubmitted => "JobSubmitted",
+            EventType::JobDone => "JobDone",
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use clap::{ArgGroup, Parser};
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context, EventType};
+use crate::cli::cmd::notify::channel;
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::NotificationHook;
+use crate::http::Error as HttpError;
+
+/// Create a new notification hook.
+///
+/// A hook sends the given events to all of its channels.
+#[derive(Parser, Debug)]
+#[command(group = ArgGroup::new("events").required(true))]
+pub struct Input {
+    /// The channels to notify, given by their id (can be abbreviated
+    /// to a prefix) or name.
+    #[arg(long = "channel", required = true)]
+    pub channels: Vec<String>,
+
+    /// The events to notify about.
+    #[arg(long = "event", value_enum, group = "events")]
+    pub events: Vec<EventType>,
+
+    /// Notify about all events.
+    #[arg(long, group = "events")]
+    pub all_events: bool,
+
+    /// An optional filter applied to the event data, see
+    /// <https://docspell.org/docs/webapp/notification/>
+    #[arg(long)]
+    pub filter: Option<String>,
+
+    /// Create the hook disabled.
+    #[arg(long)]
+    pub disabled: bool,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let channels = channel::resolve_channels(&self.channels, ctx).context(HttpClientSnafu)?;
+        let hook = NotificationHook {
+            id: "".into(),
+            enabled: !self.disabled,
+            channels,
+            all_events: self.all_events,
+            event_filter: self.filter.clone(),
+            events: self.events.iter().map(|e| e.to_value().into()).collect(),
+        };
+        let result = ctx
+            .client
+            .create_hook(&ctx.opts.session, &hook)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+pub mod add;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+use clap::{Parser, ValueEnum};
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+
+/// Manage organizations.
+#[derive(Parser, std::fmt::Debug)]
+pub struct Input {
+    #[command(subcommand)]
+    pub subcmd: OrgCommand,
+}
+
+#[derive(Parser, Debug)]
+pub enum OrgCommand {
+    #[command(version)]
+    List(list::Input),
+
+    #[command(version)]
+    Add(add::Input),
+
+    #[command(version)]
+    Update(update::Input),
+
+    #[command(version)]
+    Delete(delete::Input),
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    List { source: list::Error },
+    Add { source: add::Error },
+    Update { source: update::Error },
+    Delete { source: delete::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        match &self.subcmd {
+            OrgCommand::List(input) => input.exec(ctx).context(ListSnafu),
+            OrgCommand::Add(input) => input.exec(ctx).context(AddSnafu),
+            OrgCommand::Update(input) => input.exec(ctx).context(UpdateSnafu),
+            OrgCommand::Delete(input) => input.exec(ctx).context(DeleteSnafu),
+        }
+    }
+}
+
+/// Defines how an organization is used.
+#[derive(ValueEnum, Debug, Clone)]
+pub enum OrgUse {
+    /// The organization can be used as correspondent.
+    Correspondent,
+    /// The organization is hidden from suggestions.
+    Disabled,
+}
+impl OrgUse {
+    pub fn to_value(&self) -> &'static str {
+        match self {
+            OrgUse::Correspondent => "correspondent",
+            OrgUse::Disabled => "disabled",
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context, OrgUse};
+use crate::cli::opts::ContactOpts;
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::{Address, Organization};
+use crate::http::Error as HttpError;
+
+/// Create a new organization.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The name of the organization.
+    #[arg(long)]
+    pub name: String,
+
+    /// An optional short name.
+    #[arg(long)]
+    pub short_name: Option<String>,
+
+    #[clap(flatten)]
+    pub contact: ContactOpts,
+
+    /// Some notes about the organization.
+    #[arg(long)]
+    pub notes: Option<String>,
+
+    /// How the organization is used.
+    #[arg(long = "use", value_enum, default_value = "correspondent")]
+    pub org_use: OrgUse,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let mut address = Address::default();
+        self.contact.update_address(&mut address);
+        let org = Organization {
+            id: "".into(),
+            name: self.name.clone(),
+            short_name: self.short_name.clone(),
+            address,
+            contacts: self.contact.to_contacts().unwrap_or_default(),
+            notes: self.notes.clone(),
+            org_use: self.org_use.to_value().into(),
+            created: 0,
+        };
+        let result = ctx
+            .client
+            .create_organization(&ctx.opts.session, &org)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::Error as HttpError;
+
+/// List organizations of your collective.
+#[derive(Parser, std::fmt::Debug)]
+pub struct Input {
+    /// Filter organizations by name. The `*` wildcard can be used at
+    /// the beginning or end.
+    #[arg(long)]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let query = self.name.as_deref().unwrap_or("");
+        let items = ctx
+            .client
+            .list_organizations(&ctx.opts.session, query)
+            .map(|r| r.items)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(items).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context, OrgUse};
+use crate::cli::opts::ContactOpts;
+use crate::cli::sink::Error as SinkError;
+use crate::http::Error as HttpError;
+
+/// Change properties of an organization.
+///
+/// Only the given properties are changed, all others are kept.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The organization to change, given by its id (can be
+    /// abbreviated to a prefix) or name.
+    #[arg(long)]
+    pub id: String,
+
+    /// Set a new name.
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Set a new short name.
+    #[arg(long)]
+    pub short_name: Option<String>,
+
+    #[clap(flatten)]
+    pub contact: ContactOpts,
+
+    /// Set new notes.
+    #[arg(long)]
+    pub notes: Option<String>,
+
+    /// Change how the organization is used.
+    #[arg(long = "use", value_enum)]
+    pub org_use: Option<OrgUse>,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let mut org = ctx
+            .client
+            .find_organization(&ctx.opts.session, &self.id)
+            .context(HttpClientSnafu)?;
+
+        if let Some(name) = &self.name {
+            org.name = name.clone();
+        }
+        if let Some(short_name) = &self.short_name {
+            org.short_name = Some(short_name.clone());
+        }
+        self.contact.update_address(&mut org.address);
+        if let Some(contacts) = self.contact.to_contacts() {
+            org.contacts = contacts;
+        }
+        if let Some(notes) = &self.notes {
+            org.notes = Some(notes.clone());
+        }
+        if let Some(org_use) = &self.org_use {
+            org.org_use = org_use.to_value().into();
+        }
+
+        let result = ctx
+            .client
+            .update_organization(&ctx.opts.session, &org)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+pub mod add;
+pub mod delete;
+pub mod list;
+pub mod run_now;
+pub mod update;
+
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::http::Error as HttpError;
+
+/// Manage periodic queries.
+///
+/// A periodic query runs a query according to a schedule and sends
+/// the results to notification channels. The query is given directly
+/// or as a bookmark. Schedules are calendar events, like
+/// `Mon *-*-* 08:00` for every monday morning.
+#[derive(Parser, std::fmt::Debug)]
+pub struct Input {
+    #[command(subcommand)]
+    pub subcmd: PeriodicQueryCommand,
+}
+
+#[derive(Parser, Debug)]
+pub enum PeriodicQueryCommand {
+    #[command(version)]
+    List(list::Input),
+
+    #[command(version)]
+    Add(add::Input),
+
+    #[command(version)]
+    Update(update::Input),
+
+    #[command(version)]
+    Delete(delete::Input),
+
+    #[command(version)]
+    RunNow(run_now::Input),
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    List { source: list::Error },
+    Add { source: add::Error },
+    Update { source: update::Error },
+    Delete { source: delete::Error },
+    RunNow { source: run_now::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        match &self.subcmd {
+            PeriodicQueryCommand::List(input) => input.exec(ctx).context(ListSnafu),
+            PeriodicQueryCommand::Add(input) => input.exec(ctx).context(AddSnafu),
+            PeriodicQueryCommand::Update(input) => input.exec(ctx).context(UpdateSnafu),
+            PeriodicQueryCommand::Delete(input) => input.exec(ctx).context(DeleteSnafu),
+            PeriodicQueryCommand::RunNow(input) => input.exec(ctx).context(RunNowSnafu),
+        }
+    }
+}
+
+/// Resolves a bookmark given by its id (can be abbreviated to a
+/// prefix) or name to its id.
+fn resolve_bookmark(ctx: &Context, bookmark: &str) -> Result<String, HttpError> {
+    ctx.client
+        .find_bookmark(&ctx.opts.session, bookmark)
+        .map(|b| b.id)
+}
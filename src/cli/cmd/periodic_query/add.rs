@@ -0,0 +1,81 @@
+use clap::{ArgGroup, Parser};
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::cmd::notify::channel;
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::PeriodicQuerySettings;
+use crate::http::Error as HttpError;
+
+/// Create a new periodic query.
+#[derive(Parser, Debug)]
+#[command(group = ArgGroup::new("search").required(true))]
+pub struct Input {
+    /// A short description of the periodic query.
+    #[arg(long)]
+    pub summary: Option<String>,
+
+    /// When to run the query, as a calendar event like `Mon *-*-*
+    /// 08:00`.
+    #[arg(long)]
+    pub schedule: String,
+
+    /// The query to run. See <https://docspell.org/docs/query/>
+    #[arg(long, short, group = "search")]
+    pub query: Option<String>,
+
+    /// Run the query of this bookmark, given by its id (can be
+    /// abbreviated to a prefix) or name.
+    #[arg(long, group = "search")]
+    pub bookmark: Option<String>,
+
+    /// The channels to send the results to, given by their id (can be
+    /// abbreviated to a prefix) or name.
+    #[arg(long = "channel", required = true)]
+    pub channels: Vec<String>,
+
+    /// An optional text that is put before the results.
+    #[arg(long)]
+    pub content_start: Option<String>,
+
+    /// Create the periodic query disabled.
+    #[arg(long)]
+    pub disabled: bool,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let channels = channel::resolve_channels(&self.channels, ctx).context(HttpClientSnafu)?;
+        let bookmark = match &self.bookmark {
+            Some(b) => Some(super::resolve_bookmark(ctx, b).context(HttpClientSnafu)?),
+            None => None,
+        };
+        let settings = PeriodicQuerySettings {
+            id: "".into(),
+            enabled: !self.disabled,
+            summary: self.summary.clone(),
+            channels,
+            schedule: self.schedule.clone(),
+            query: self.query.clone(),
+            bookmark,
+            content_start: self.content_start.clone(),
+        };
+        let result = ctx
+            .client
+            .create_periodic_query(&ctx.opts.session, &settings)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use clap::{ArgGroup, Parser};
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::cmd::notify::channel;
+use crate::cli::sink::Error as SinkError;
+use crate::http::Error as HttpError;
+
+/// Change a periodic query.
+///
+/// Only the given properties are changed, all others are kept.
+/// Setting a query removes the bookmark and vice versa.
+#[derive(Parser, Debug)]
+#[command(group = ArgGroup::new("search"))]
+pub struct Input {
+    /// The periodic query to change, given by its id (can be
+    /// abbreviated to a prefix) or summary.
+    #[arg(long)]
+    pub id: String,
+
+    /// Set a new summary.
+    #[arg(long)]
+    pub summary: Option<String>,
+
+    /// Set a new schedule, as a calendar event like `Mon *-*-*
+    /// 08:00`.
+    #[arg(long)]
+    pub schedule: Option<String>,
+
+    /// Set a new query. See <https://docspell.org/docs/query/>
+    #[arg(long, short, group = "search")]
+    pub query: Option<String>,
+
+    /// Use the query of this bookmark, given by its id (can be
+    /// abbreviated to a prefix) or name.
+    #[arg(long, group = "search")]
+    pub bookmark: Option<String>,
+
+    /// Replace the channels, given by their id (can be abbreviated to
+    /// a prefix) or name.
+    #[arg(long = "channel")]
+    pub channels: Vec<String>,
+
+    /// Set a new text that is put before the results.
+    #[arg(long)]
+    pub content_start: Option<String>,
+
+    /// Whether the periodic query is enabled.
+    #[arg(long)]
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let mut task = ctx
+            .client
+            .find_periodic_query(&ctx.opts.session, &self.id)
+            .context(HttpClientSnafu)?;
+
+        if let Some(summary) = &self.summary {
+            task.summary = Some(summary.clone());
+        }
+        if let Some(schedule) = &self.schedule {
+            task.schedule = schedule.clone();
+        }
+        if let Some(query) = &self.query {
+            task.query = Some(query.clone());
+            task.bookmark = None;
+        }
+        if let Some(bookmark) = &self.bookmark {
+            task.bookmark = Some(super::resolve_bookmark(ctx, bookmark).context(HttpClientSnafu)?);
+            task.query = None;
+        }
+        if !self.channels.is_empty() {
+            task.channels =
+                channel::resolve_channels(&self.channels, ctx).context(HttpClientSnafu)?;
+        }
+        if let Some(content_start) = &self.content_start {
+            task.content_start = Some(content_start.clone());
+        }
+        if let Some(enabled) = self.enabled {
+            task.enabled = enabled;
+        }
+
+        let result = ctx
+            .client
+            .update_periodic_query(&ctx.opts.session, &task)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+pub mod add;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+use clap::{Parser, ValueEnum};
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+
+/// Manage persons.
+#[derive(Parser, std::fmt::Debug)]
+pub struct Input {
+    #[command(subcommand)]
+    pub subcmd: PersonCommand,
+}
+
+#[derive(Parser, Debug)]
+pub enum PersonCommand {
+    #[command(version)]
+    List(list::Input),
+
+    #[command(version)]
+    Add(add::Input),
+
+    #[command(version)]
+    Update(update::Input),
+
+    #[command(version)]
+    Delete(delete::Input),
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    List { source: list::Error },
+    Add { source: add::Error },
+    Update { source: update::Error },
+    Delete { source: delete::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        match &self.subcmd {
+            PersonCommand::List(input) => input.exec(ctx).context(ListSnafu),
+            PersonCommand::Add(input) => input.exec(ctx).context(AddSnafu),
+            PersonCommand::Update(input) => input.exec(ctx).context(UpdateSnafu),
+            PersonCommand::Delete(input) => input.exec(ctx).context(DeleteSnafu),
+        }
+    }
+}
+
+/// Defines how a person is used when suggesting metadata for items.
+#[derive(ValueEnum, Debug, Clone)]
+pub enum PersonUse {
+    /// The person can be used as correspondent.
+    Correspondent,
+    /// The person can be used as concerning person.
+    Concerning,
+    /// The person can be used as correspondent and concerning person.
+    Both,
+    /// The person is hidden from suggestions.
+    Disabled,
+}
+impl PersonUse {
+    pub fn to_value(&self) -> &'static str {
+        match self {
+            PersonUse::Correspondent => "correspondent",
+            PersonUse::Concerning => "concerning",
+            PersonUse::Both => "both",
+            PersonUse::Disabled => "disabled",
+        }
+    }
+}
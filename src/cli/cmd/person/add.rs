@@ -0,0 +1,80 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context, PersonUse};
+use crate::cli::opts::ContactOpts;
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::{Address, IdName, Person};
+use crate::http::Error as HttpError;
+
+/// Create a new person.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The name of the person.
+    #[arg(long)]
+    pub name: String,
+
+    /// The organization the person belongs to, given by its id (can
+    /// be abbreviated to a prefix) or name.
+    #[arg(long)]
+    pub org: Option<String>,
+
+    #[clap(flatten)]
+    pub contact: ContactOpts,
+
+    /// Some notes about the person.
+    #[arg(long)]
+    pub notes: Option<String>,
+
+    /// Whether the person is used as correspondent, concerning
+    /// person or both.
+    #[arg(long = "use", value_enum, default_value = "both")]
+    pub person_use: PersonUse,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let organization = match &self.org {
+            Some(org) => {
+                let o = ctx
+                    .client
+                    .find_organization(&ctx.opts.session, org)
+                    .context(HttpClientSnafu)?;
+                Some(IdName {
+                    id: o.id,
+                    name: o.name,
+                })
+            }
+            None => None,
+        };
+        let mut address = Address::default();
+        self.contact.update_address(&mut address);
+        let person = Person {
+            id: "".into(),
+            name: self.name.clone(),
+            organization,
+            address,
+            contacts: self.contact.to_contacts().unwrap_or_default(),
+            notes: self.notes.clone(),
+            person_use: self.person_use.to_value().into(),
+            created: 0,
+        };
+        let result = ctx
+            .client
+            .create_person(&ctx.opts.session, &person)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
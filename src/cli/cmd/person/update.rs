@@ -0,0 +1,98 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context, PersonUse};
+use crate::cli::opts::ContactOpts;
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::IdName;
+use crate::http::Error as HttpError;
+
+/// Change properties of a person.
+///
+/// Only the given properties are changed, all others are kept.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The person to change, given by its id (can be abbreviated to a
+    /// prefix) or name.
+    #[arg(long)]
+    pub id: String,
+
+    /// Set a new name.
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Set the organization, given by its id (can be abbreviated to a
+    /// prefix) or name.
+    #[arg(long)]
+    pub org: Option<String>,
+
+    /// Remove the organization from the person.
+    #[arg(long, conflicts_with = "org")]
+    pub remove_org: bool,
+
+    #[clap(flatten)]
+    pub contact: ContactOpts,
+
+    /// Set new notes.
+    #[arg(long)]
+    pub notes: Option<String>,
+
+    /// Change whether the person is used as correspondent, concerning
+    /// person or both.
+    #[arg(long = "use", value_enum)]
+    pub person_use: Option<PersonUse>,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let mut person = ctx
+            .client
+            .find_person(&ctx.opts.session, &self.id)
+            .context(HttpClientSnafu)?;
+
+        if let Some(name) = &self.name {
+            person.name = name.clone();
+        }
+        if let Some(org) = &self.org {
+            let o = ctx
+                .client
+                .find_organization(&ctx.opts.session, org)
+                .context(HttpClientSnafu)?;
+            person.organization = Some(IdName {
+                id: o.id,
+                name: o.name,
+            });
+        }
+        if self.remove_org {
+            person.organization = None;
+        }
+        self.contact.update_address(&mut person.address);
+        if let Some(contacts) = self.contact.to_contacts() {
+            person.contacts = contacts;
+        }
+        if let Some(notes) = &self.notes {
+            person.notes = Some(notes.clone());
+        }
+        if let Some(person_use) = &self.person_use {
+            person.person_use = person_use.to_value().into();
+        }
+
+        let result = ctx
+            .client
+            .update_person(&ctx.opts.session, &person)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+pub mod cancel;
+pub mod state;
+
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+
+/// Inspect the job queue.
+///
+/// Uploaded files are processed by jobs on the server. This shows how
+/// far processing has got and allows to cancel jobs.
+#[derive(Parser, std::fmt::Debug)]
+pub struct Input {
+    #[command(subcommand)]
+    pub subcmd: QueueCommand,
+}
+
+#[derive(Parser, Debug)]
+pub enum QueueCommand {
+    #[command(version)]
+    State(state::Input),
+
+    #[command(version)]
+    Cancel(cancel::Input),
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    State { source: state::Error },
+    Cancel { source: cancel::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        match &self.subcmd {
+            QueueCommand::State(input) => input.exec(ctx).context(StateSnafu),
+            QueueCommand::Cancel(input) => input.exec(ctx).context(CancelSnafu),
+        }
+    }
+}
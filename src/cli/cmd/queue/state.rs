@@ -0,0 +1,39 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::Error as HttpError;
+
+/// Show running, queued and recently completed jobs.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// Don't show completed jobs.
+    #[arg(long)]
+    pub active: bool,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let mut state = ctx
+            .client
+            .get_queue_state(&ctx.opts.session)
+            .context(HttpClientSnafu)?;
+        if self.active {
+            state.completed.clear();
+        }
+        ctx.write_result(state).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
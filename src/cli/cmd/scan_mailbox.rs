@@ -0,0 +1,71 @@
+pub mod add;
+pub mod delete;
+pub mod list;
+pub mod run;
+pub mod update;
+
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::http::Error as HttpError;
+
+/// Manage scan-mailbox tasks.
+///
+/// A scan-mailbox task periodically imports mails from folders of an
+/// IMAP connection (see `mail-settings imap`). Schedules are calendar
+/// events, like `*-*-* 0/4:00` for every four hours.
+#[derive(Parser, std::fmt::Debug)]
+pub struct Input {
+    #[command(subcommand)]
+    pub subcmd: ScanMailboxCommand,
+}
+
+#[derive(Parser, Debug)]
+pub enum ScanMailboxCommand {
+    #[command(version)]
+    List(list::Input),
+
+    #[command(version)]
+    Add(add::Input),
+
+    #[command(version)]
+    Update(update::Input),
+
+    #[command(version)]
+    Delete(delete::Input),
+
+    #[command(version)]
+    Run(run::Input),
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    List { source: list::Error },
+    Add { source: add::Error },
+    Update { source: update::Error },
+    Delete { source: delete::Error },
+    Run { source: run::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        match &self.subcmd {
+            ScanMailboxCommand::List(input) => input.exec(ctx).context(ListSnafu),
+            ScanMailboxCommand::Add(input) => input.exec(ctx).context(AddSnafu),
+            ScanMailboxCommand::Update(input) => input.exec(ctx).context(UpdateSnafu),
+            ScanMailboxCommand::Delete(input) => input.exec(ctx).context(DeleteSnafu),
+            ScanMailboxCommand::Run(input) => input.exec(ctx).context(RunSnafu),
+        }
+    }
+}
+
+/// Resolves a folder given by its id (can be abbreviated to a prefix)
+/// or name to its id.
+fn resolve_folder(ctx: &Context, folder: &str) -> Result<String, HttpError> {
+    ctx.client
+        .find_folder(&ctx.opts.session, folder)
+        .map(|f| f.id)
+}
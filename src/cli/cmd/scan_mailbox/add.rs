@@ -0,0 +1,131 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::cmd::source::resolve_tags;
+use crate::cli::opts::Direction;
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::{ScanMailboxSettings, StringList};
+use crate::http::Error as HttpError;
+
+/// Create a new scan-mailbox task.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// A short description of the task.
+    #[arg(long)]
+    pub summary: Option<String>,
+
+    /// The name of the IMAP connection to use.
+    #[arg(long)]
+    pub imap_connection: String,
+
+    /// When to run the task, as a calendar event like `*-*-* 0/4:00`.
+    #[arg(long)]
+    pub schedule: String,
+
+    /// The mailbox folders to scan, like `INBOX`.
+    #[arg(long = "folder", required = true)]
+    pub folders: Vec<String>,
+
+    /// Only import mails received within the last hours.
+    #[arg(long)]
+    pub received_since_hours: Option<u32>,
+
+    /// Move imported mails into this mailbox folder.
+    #[arg(long)]
+    pub target_folder: Option<String>,
+
+    /// Delete imported mails from the mailbox.
+    #[arg(long)]
+    pub delete_mail: bool,
+
+    /// Set this direction on the created items.
+    #[arg(long, value_enum)]
+    pub direction: Option<Direction>,
+
+    /// Put the created items into this folder, given by its id (can
+    /// be abbreviated to a prefix) or name.
+    #[arg(long)]
+    pub item_folder: Option<String>,
+
+    /// Only import attachments matching this glob pattern.
+    #[arg(long)]
+    pub file_filter: Option<String>,
+
+    /// Tag the created items with these tags. Can be ids or names.
+    #[arg(long = "tag")]
+    pub tags: Vec<String>,
+
+    /// Only import mails whose subject matches this glob pattern.
+    #[arg(long)]
+    pub subject_filter: Option<String>,
+
+    /// The language of the mails.
+    #[arg(long)]
+    pub language: Option<String>,
+
+    /// Move or delete all matching mails, even those that are
+    /// skipped because they already exist.
+    #[arg(long)]
+    pub post_handle_all: bool,
+
+    /// Only import the attachments of mails, not the mail itself.
+    #[arg(long)]
+    pub attachments_only: bool,
+
+    /// Create the task disabled.
+    #[arg(long)]
+    pub disabled: bool,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let item_folder = match &self.item_folder {
+            Some(f) => Some(super::resolve_folder(ctx, f).context(HttpClientSnafu)?),
+            None => None,
+        };
+        let tags = if self.tags.is_empty() {
+            None
+        } else {
+            Some(StringList {
+                items: resolve_tags(ctx, &self.tags).context(HttpClientSnafu)?,
+            })
+        };
+        let settings = ScanMailboxSettings {
+            id: "".into(),
+            enabled: !self.disabled,
+            summary: self.summary.clone(),
+            imap_connection: self.imap_connection.clone(),
+            schedule: self.schedule.clone(),
+            folders: self.folders.clone(),
+            received_since_hours: self.received_since_hours,
+            target_folder: self.target_folder.clone(),
+            delete_mail: self.delete_mail,
+            direction: self.direction.as_ref().map(|d| d.to_value().into()),
+            item_folder,
+            file_filter: self.file_filter.clone(),
+            tags,
+            subject_filter: self.subject_filter.clone(),
+            language: self.language.clone(),
+            post_handle_all: Some(self.post_handle_all),
+            attachments_only: Some(self.attachments_only),
+        };
+        let result = ctx
+            .client
+            .create_scan_mailbox(&ctx.opts.session, &settings)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
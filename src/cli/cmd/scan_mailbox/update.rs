@@ -0,0 +1,214 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::cmd::source::resolve_tags;
+use crate::cli::opts::Direction;
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::StringList;
+use crate::http::Error as HttpError;
+
+/// Change a scan-mailbox task.
+///
+/// Only the given properties are changed, all others are kept.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The task to change, given by its id (can be abbreviated to a
+    /// prefix) or summary.
+    #[arg(long)]
+    pub id: String,
+
+    /// Set a new summary.
+    #[arg(long)]
+    pub summary: Option<String>,
+
+    /// Use another IMAP connection, given by its name.
+    #[arg(long)]
+    pub imap_connection: Option<String>,
+
+    /// Set a new schedule, as a calendar event like `*-*-* 0/4:00`.
+    #[arg(long)]
+    pub schedule: Option<String>,
+
+    /// Replace the mailbox folders to scan.
+    #[arg(long = "folder")]
+    pub folders: Vec<String>,
+
+    /// Only import mails received within the last hours.
+    #[arg(long)]
+    pub received_since_hours: Option<u32>,
+
+    /// Import mails regardless of when they were received.
+    #[arg(long, conflicts_with = "received_since_hours")]
+    pub remove_received_since_hours: bool,
+
+    /// Move imported mails into this mailbox folder.
+    #[arg(long)]
+    pub target_folder: Option<String>,
+
+    /// Don't move imported mails into another mailbox folder.
+    #[arg(long, conflicts_with = "target_folder")]
+    pub remove_target_folder: bool,
+
+    /// Whether to delete imported mails from the mailbox.
+    #[arg(long)]
+    pub delete_mail: Option<bool>,
+
+    /// Set this direction on the created items.
+    #[arg(long, value_enum)]
+    pub direction: Option<Direction>,
+
+    /// Put the created items into this folder, given by its id (can
+    /// be abbreviated to a prefix) or name.
+    #[arg(long)]
+    pub item_folder: Option<String>,
+
+    /// Don't put the created items into a folder.
+    #[arg(long, conflicts_with = "item_folder")]
+    pub remove_item_folder: bool,
+
+    /// Only import attachments matching this glob pattern.
+    #[arg(long)]
+    pub file_filter: Option<String>,
+
+    /// Import all attachments.
+    #[arg(long, conflicts_with = "file_filter")]
+    pub remove_file_filter: bool,
+
+    /// Replace the tags for the created items. Can be ids or names.
+    #[arg(long = "tag")]
+    pub tags: Vec<String>,
+
+    /// Don't add tags to the created items.
+    #[arg(long, conflicts_with = "tags")]
+    pub clear_tags: bool,
+
+    /// Only import mails whose subject matches this glob pattern.
+    #[arg(long)]
+    pub subject_filter: Option<String>,
+
+    /// Import mails with any subject.
+    #[arg(long, conflicts_with = "subject_filter")]
+    pub remove_subject_filter: bool,
+
+    /// The language of the mails.
+    #[arg(long)]
+    pub language: Option<String>,
+
+    /// Use the default language of the collective.
+    #[arg(long, conflicts_with = "language")]
+    pub remove_language: bool,
+
+    /// Whether to move or delete all matching mails, even those that
+    /// are skipped because they already exist.
+    #[arg(long)]
+    pub post_handle_all: Option<bool>,
+
+    /// Whether to only import the attachments of mails, not the mail
+    /// itself.
+    #[arg(long)]
+    pub attachments_only: Option<bool>,
+
+    /// Whether the task is enabled.
+    #[arg(long)]
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let mut task = ctx
+            .client
+            .find_scan_mailbox(&ctx.opts.session, &self.id)
+            .context(HttpClientSnafu)?;
+
+        if let Some(summary) = &self.summary {
+            task.summary = Some(summary.clone());
+        }
+        if let Some(conn) = &self.imap_connection {
+            task.imap_connection = conn.clone();
+        }
+        if let Some(schedule) = &self.schedule {
+            task.schedule = schedule.clone();
+        }
+        if !self.folders.is_empty() {
+            task.folders = self.folders.clone();
+        }
+        if let Some(hours) = self.received_since_hours {
+            task.received_since_hours = Some(hours);
+        }
+        if self.remove_received_since_hours {
+            task.received_since_hours = None;
+        }
+        if let Some(folder) = &self.target_folder {
+            task.target_folder = Some(folder.clone());
+        }
+        if self.remove_target_folder {
+            task.target_folder = None;
+        }
+        if let Some(delete) = self.delete_mail {
+            task.delete_mail = delete;
+        }
+        if let Some(direction) = &self.direction {
+            task.direction = Some(direction.to_value().into());
+        }
+        if let Some(folder) = &self.item_folder {
+            task.item_folder = Some(super::resolve_folder(ctx, folder).context(HttpClientSnafu)?);
+        }
+        if self.remove_item_folder {
+            task.item_folder = None;
+        }
+        if let Some(filter) = &self.file_filter {
+            task.file_filter = Some(filter.clone());
+        }
+        if self.remove_file_filter {
+            task.file_filter = None;
+        }
+        if !self.tags.is_empty() {
+            task.tags = Some(StringList {
+                items: resolve_tags(ctx, &self.tags).context(HttpClientSnafu)?,
+            });
+        }
+        if self.clear_tags {
+            task.tags = None;
+        }
+        if let Some(filter) = &self.subject_filter {
+            task.subject_filter = Some(filter.clone());
+        }
+        if self.remove_subject_filter {
+            task.subject_filter = None;
+        }
+        if let Some(lang) = &self.language {
+            task.language = Some(lang.clone());
+        }
+        if self.remove_language {
+            task.language = None;
+        }
+        if let Some(flag) = self.post_handle_all {
+            task.post_handle_all = Some(flag);
+        }
+        if let Some(flag) = self.attachments_only {
+            task.attachments_only = Some(flag);
+        }
+        if let Some(enabled) = self.enabled {
+            task.enabled = enabled;
+        }
+
+        let result = ctx
+            .client
+            .update_scan_mailbox(&ctx.opts.session, &task)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+pub mod create;
+pub mod delete;
+pub mod fetch;
+pub mod list;
+pub mod update;
+
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::cmd;
+
+/// Manage public shares.
+///
+/// A share publishes the results of a query via a public link. The
+/// link can be protected by a password and is only valid until a
+/// given date. Shares can be fetched without an account.
+#[derive(Parser, std::fmt::Debug)]
+pub struct Input {
+    #[command(subcommand)]
+    pub subcmd: ShareCommand,
+}
+
+#[derive(Parser, Debug)]
+pub enum ShareCommand {
+    #[command(version)]
+    List(list::Input),
+
+    #[command(version)]
+    Create(create::Input),
+
+    #[command(version)]
+    Update(update::Input),
+
+    #[command(version)]
+    Delete(delete::Input),
+
+    #[command(version)]
+    Fetch(fetch::Input),
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    List { source: list::Error },
+    Create { source: create::Error },
+    Update { source: update::Error },
+    Delete { source: delete::Error },
+    Fetch { source: fetch::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        match &self.subcmd {
+            ShareCommand::List(input) => input.exec(ctx).context(ListSnafu),
+            ShareCommand::Create(input) => input.exec(ctx).context(CreateSnafu),
+            ShareCommand::Update(input) => input.exec(ctx).context(UpdateSnafu),
+            ShareCommand::Delete(input) => input.exec(ctx).context(DeleteSnafu),
+            ShareCommand::Fetch(input) => input.exec(ctx).context(FetchSnafu),
+        }
+    }
+}
+
+/// Creates the public url to the share with the given id.
+pub fn create_url(ctx: &Context, share_id: &str) -> String {
+    let base_url = cmd::docspell_url(ctx.opts, ctx.cfg);
+    format!("{}/app/share/{}", base_url, share_id)
+}
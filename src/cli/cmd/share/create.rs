@@ -0,0 +1,107 @@
+use chrono::{Duration, Utc};
+use clap::Parser;
+use prettytable::{row, Table};
+use serde::{Deserialize, Serialize};
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::opts::parse_date;
+use crate::cli::sink::{Error as SinkError, Sink};
+use crate::cli::table;
+use crate::http::payload::ShareData;
+use crate::http::Error as HttpError;
+
+/// Create a new share for the results of a query.
+///
+/// Prints the public url of the share on success. Without `--until`,
+/// the share is published for 30 days.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The query whose results are shared. See
+    /// <https://docspell.org/docs/query/>
+    #[arg(long, short)]
+    pub query: String,
+
+    /// An optional name for the share.
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Protect the share with a password.
+    #[arg(long)]
+    pub password: Option<String>,
+
+    /// The date (`YYYY-MM-DD`) until the share is published.
+    #[arg(long, value_parser = parse_date)]
+    pub until: Option<i64>,
+
+    /// Create the share disabled.
+    #[arg(long)]
+    pub disabled: bool,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CmdResult {
+    pub success: bool,
+    pub message: String,
+    pub id: Option<String>,
+    pub url: Option<String>,
+}
+impl table::AsTable for CmdResult {
+    fn to_table(&self) -> Table {
+        let mut table = table::mk_table();
+        table.set_titles(row![bFg => "success", "message", "id", "url"]);
+        table.add_row(row![
+            self.success,
+            self.message,
+            self.id.clone().unwrap_or_else(|| String::from("-")),
+            self.url.clone().unwrap_or_else(|| String::from("-")),
+        ]);
+        table
+    }
+}
+impl Sink for CmdResult {}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let until = self
+            .until
+            .unwrap_or_else(|| (Utc::now() + Duration::days(30)).timestamp_millis());
+        let data = ShareData {
+            name: self.name.clone(),
+            query: self.query.clone(),
+            enabled: !self.disabled,
+            password: self.password.clone(),
+            publish_until: until,
+            remove_password: false,
+        };
+        let result = ctx
+            .client
+            .create_share(&ctx.opts.session, &data)
+            .context(HttpClientSnafu)?;
+        let (id, url) = if result.success {
+            let url = super::create_url(ctx, &result.id);
+            (Some(result.id), Some(url))
+        } else {
+            (None, None)
+        };
+        let out = CmdResult {
+            success: result.success,
+            message: result.message,
+            id,
+            url,
+        };
+        ctx.write_result(out).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
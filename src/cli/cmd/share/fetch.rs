@@ -0,0 +1,216 @@
+use clap::{ArgGroup, Parser};
+use snafu::{ResultExt, Snafu};
+use std::path::PathBuf;
+
+use super::{Cmd, Context};
+use crate::cli::cmd::download::{self, DownloadOpts, DupeMode};
+use crate::cli::cmd::export::{self, ExportOpts};
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::{self, SearchReq, SearchResult};
+use crate::http::{DownloadAuth, Downloads, Error as HttpError};
+
+/// Download the files of a public share.
+///
+/// This doesn't require an account, only the id of the share (the
+/// last part of its url) and its password, if it has one. The files
+/// are downloaded like with the `download` command. With `--export`,
+/// the items are exported including their metadata like with the
+/// `export` command. Use `--list` to only show the shared items.
+///
+/// A share only provides the converted files and preview images, the
+/// original files are not available.
+///
+/// At most `--limit` items are fetched. A warning is printed if there
+/// may be more, which can be fetched using `--offset`. When
+/// exporting, `--all` exports all items.
+#[derive(Parser, Debug)]
+#[command(group = ArgGroup::new("mode"))]
+pub struct Input {
+    /// The id of the share, which is the last part of its url.
+    pub share_id: String,
+
+    /// The password of the share, if it is protected.
+    #[arg(long)]
+    pub password: Option<String>,
+
+    /// An optional query to restrict the shared items further. See
+    /// <https://docspell.org/docs/query/>
+    #[arg(long, short, default_value = "")]
+    pub query: String,
+
+    /// Only list the shared items.
+    #[arg(long, group = "mode")]
+    pub list: bool,
+
+    /// Export the items with their metadata instead of only
+    /// downloading the files.
+    #[arg(long, group = "mode")]
+    pub export: bool,
+
+    /// Limit the number of results.
+    #[arg(short, long, default_value = "60")]
+    pub limit: u32,
+
+    /// Skip the first n results.
+    #[arg(short, long, default_value = "0")]
+    pub offset: u32,
+
+    /// Export all items, by incrementing the offset until all items
+    /// have been exported.
+    #[arg(long, requires = "export")]
+    pub all: bool,
+
+    /// Whether to overwrite already existing files.
+    #[arg(long)]
+    pub overwrite: bool,
+
+    /// Download the preview image of each attachment.
+    #[arg(long)]
+    pub preview: bool,
+
+    /// Creates a single zip file containing all files (flat).
+    #[arg(long)]
+    pub zip: bool,
+
+    /// What to do when multiple files map to the same name.
+    #[arg(long, value_enum, default_value = "rename")]
+    pub dupes: DupeMode,
+
+    /// Download everything into this directory. If not given, the
+    /// current working directory is used. If `--zip` is used, this is
+    /// the zip file to create.
+    #[arg(short, long)]
+    pub target: Option<PathBuf>,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+
+    #[snafu(display("The share requires a password, use --password"))]
+    PasswordRequired,
+
+    #[snafu(display("Accessing the share failed: {}", message))]
+    Verify { message: String },
+
+    #[snafu(display("Cannot get current directory: {}", source))]
+    CurrentDir { source: std::io::Error },
+
+    #[snafu(display("{}", source))]
+    Download { source: download::Error },
+
+    #[snafu(display("{}", source))]
+    Export { source: export::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let auth = verify(self, ctx)?;
+        if self.list {
+            let result = self.search(ctx, &auth, false)?;
+            ctx.write_result(result).context(WriteResultSnafu)?;
+        } else if self.export {
+            let count = export::export_all(&self.export_input()?.into(), ctx, &auth)
+                .context(ExportSnafu)?;
+            if !self.all {
+                self.warn_limit(count);
+            }
+        } else {
+            let input = self.download_input().into();
+            download::check_args(&input).context(DownloadSnafu)?;
+            let result = self.search(ctx, &auth, true)?;
+            let attachs = Downloads::from_results(&result);
+            download::download_files(attachs, &input, ctx, &auth).context(DownloadSnafu)?;
+        }
+        Ok(())
+    }
+}
+
+impl Input {
+    /// Searches the shared items and warns if there may be more
+    /// items than returned.
+    fn search(
+        &self,
+        ctx: &Context,
+        auth: &DownloadAuth,
+        with_details: bool,
+    ) -> Result<SearchResult, Error> {
+        let req = SearchReq {
+            offset: self.offset,
+            limit: self.limit,
+            with_details,
+            query: self.query.clone(),
+            search_mode: payload::SearchMode::Normal,
+        };
+        let result = ctx
+            .client
+            .search_with(auth, &req)
+            .context(HttpClientSnafu)?;
+        self.warn_limit(result.groups.iter().map(|g| g.items.len()).sum());
+        Ok(result)
+    }
+
+    fn warn_limit(&self, count: usize) {
+        if count >= self.limit as usize {
+            eprintln!(
+                "Only the first {} items were fetched, the share may contain more. Use --offset to get them.",
+                count
+            );
+        }
+    }
+
+    fn download_input(&self) -> DownloadOpts {
+        DownloadOpts {
+            query: self.query.clone(),
+            limit: self.limit,
+            offset: self.offset,
+            overwrite: self.overwrite,
+            preview: self.preview,
+            zip: self.zip,
+            dupes: self.dupes.clone(),
+            target: self.target.clone(),
+        }
+    }
+
+    fn export_input(&self) -> Result<ExportOpts, Error> {
+        let target = match &self.target {
+            Some(dir) => dir.clone(),
+            None => std::env::current_dir().context(CurrentDirSnafu)?,
+        };
+        Ok(ExportOpts {
+            query: Some(self.query.clone()),
+            limit: self.limit,
+            offset: self.offset,
+            all: self.all,
+            overwrite: self.overwrite,
+            target,
+        })
+    }
+}
+
+/// Verifies the share id and password and returns the authentication
+/// to access the share.
+fn verify(opts: &Input, ctx: &Context) -> Result<DownloadAuth, Error> {
+    let result = ctx
+        .client
+        .verify_share(&opts.share_id, &opts.password)
+        .context(HttpClientSnafu)?;
+    if result.success {
+        if let Some(name) = &result.name {
+            log::info!("Accessing share: {}", name);
+        }
+        Ok(DownloadAuth::from_share(result.token))
+    } else if result.password_required && opts.password.is_none() {
+        Err(Error::PasswordRequired)
+    } else {
+        Err(Error::Verify {
+            message: result.message,
+        })
+    }
+}
@@ -0,0 +1,36 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::Error as HttpError;
+
+/// Lists all shares of the collective.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// Only list shares whose name contains the given text.
+    #[arg(long, default_value = "")]
+    pub name: String,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let shares = ctx
+            .client
+            .list_shares(&ctx.opts.session, &self.name)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(shares).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use clap::{ArgGroup, Parser};
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::opts::parse_date;
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::ShareData;
+use crate::http::Error as HttpError;
+
+/// Change a share.
+///
+/// Only the given properties are changed, all others are kept.
+#[derive(Parser, Debug)]
+#[command(group = ArgGroup::new("pass"))]
+pub struct Input {
+    /// The share to change, given by its id (can be abbreviated to a
+    /// prefix) or name.
+    #[arg(long)]
+    pub id: String,
+
+    /// Set a new name.
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Set a new query. See <https://docspell.org/docs/query/>
+    #[arg(long, short)]
+    pub query: Option<String>,
+
+    /// Set a new password.
+    #[arg(long, group = "pass")]
+    pub password: Option<String>,
+
+    /// Remove the password from the share.
+    #[arg(long, group = "pass")]
+    pub remove_password: bool,
+
+    /// Set a new date (`YYYY-MM-DD`) until the share is published.
+    #[arg(long, value_parser = parse_date)]
+    pub until: Option<i64>,
+
+    /// Whether the share is enabled.
+    #[arg(long)]
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let share = ctx
+            .client
+            .find_share(&ctx.opts.session, &self.id)
+            .context(HttpClientSnafu)?;
+
+        let data = ShareData {
+            name: self.name.clone().or(share.name),
+            query: self.query.clone().unwrap_or(share.query),
+            enabled: self.enabled.unwrap_or(share.enabled),
+            password: self.password.clone(),
+            publish_until: self.until.unwrap_or(share.publish_until),
+            remove_password: self.remove_password,
+        };
+        let result = ctx
+            .client
+            .update_share(&ctx.opts.session, &share.id, &data)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
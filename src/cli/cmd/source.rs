@@ -1,9 +1,16 @@
+pub mod add;
+pub mod delete;
+pub mod disable;
+pub mod enable;
 pub mod list;
+pub mod update;
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use snafu::{ResultExt, Snafu};
 
 use super::{Cmd, Context};
+use crate::http::payload::{BasicResult, SourceTagIn};
+use crate::http::Error as HttpError;
 
 /// Manage source urls for uploading files.
 #[derive(Parser, std::fmt::Debug)]
@@ -16,11 +23,31 @@ pub struct Input {
 pub enum SourceCommand {
     #[command(version)]
     List(list::Input),
+
+    #[command(version)]
+    Add(add::Input),
+
+    #[command(version)]
+    Update(update::Input),
+
+    #[command(version)]
+    Enable(enable::Input),
+
+    #[command(version)]
+    Disable(disable::Input),
+
+    #[command(version)]
+    Delete(delete::Input),
 }
 
 #[derive(Debug, Snafu)]
 pub enum Error {
     List { source: list::Error },
+    Add { source: add::Error },
+    Update { source: update::Error },
+    Enable { source: enable::Error },
+    Disable { source: disable::Error },
+    Delete { source: delete::Error },
 }
 
 impl Cmd for Input {
@@ -29,6 +56,46 @@ impl Cmd for Input {
     fn exec(&self, args: &Context) -> Result<(), Error> {
         match &self.subcmd {
             SourceCommand::List(input) => input.exec(args).context(ListSnafu),
+            SourceCommand::Add(input) => input.exec(args).context(AddSnafu),
+            SourceCommand::Update(input) => input.exec(args).context(UpdateSnafu),
+            SourceCommand::Enable(input) => input.exec(args).context(EnableSnafu),
+            SourceCommand::Disable(input) => input.exec(args).context(DisableSnafu),
+            SourceCommand::Delete(input) => input.exec(args).context(DeleteSnafu),
         }
     }
 }
+
+/// The priority of jobs created from uploads to a source.
+#[derive(ValueEnum, Debug, Clone)]
+pub enum SourcePriority {
+    Low,
+    High,
+}
+impl SourcePriority {
+    pub fn to_value(&self) -> &'static str {
+        match self {
+            SourcePriority::Low => "low",
+            SourcePriority::High => "high",
+        }
+    }
+}
+
+/// Resolves the given tags (ids or names) to their ids.
+pub fn resolve_tags(ctx: &Context, tags: &[String]) -> Result<Vec<String>, HttpError> {
+    tags.iter()
+        .map(|t| ctx.client.find_tag(&ctx.opts.session, t).map(|tag| tag.id))
+        .collect()
+}
+
+/// Enables or disables the source given by its id, prefix or name.
+/// The tags of the source are kept.
+fn set_enabled(ctx: &Context, id: &str, enabled: bool) -> Result<BasicResult, HttpError> {
+    let current = ctx.client.find_source(&ctx.opts.session, id)?;
+    let mut source = current.source;
+    source.enabled = enabled;
+    let data = SourceTagIn {
+        source,
+        tags: current.tags.items.into_iter().map(|t| t.id).collect(),
+    };
+    ctx.client.update_source(&ctx.opts.session, &data)
+}
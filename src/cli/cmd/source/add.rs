@@ -0,0 +1,96 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context, SourcePriority};
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::{Source, SourceTagIn};
+use crate::http::Error as HttpError;
+
+/// Create a new source for uploading files.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The name of the source.
+    #[arg(long)]
+    pub name: String,
+
+    /// An optional description.
+    #[arg(long)]
+    pub description: Option<String>,
+
+    /// The priority of processing jobs for uploads via this source.
+    #[arg(long, value_enum, default_value = "low")]
+    pub priority: SourcePriority,
+
+    /// Put all uploaded files into this folder, given by its id (can
+    /// be abbreviated to a prefix) or name.
+    #[arg(long)]
+    pub folder: Option<String>,
+
+    /// Only accept files matching this glob pattern, like `*.pdf`.
+    #[arg(long)]
+    pub file_filter: Option<String>,
+
+    /// The language of uploaded documents.
+    #[arg(long)]
+    pub language: Option<String>,
+
+    /// Tag all uploaded items with these tags. Can be ids or names.
+    #[arg(long = "tag")]
+    pub tags: Vec<String>,
+
+    /// Only process attachments of e-mails.
+    #[arg(long)]
+    pub attachments_only: bool,
+
+    /// Create the source in disabled state.
+    #[arg(long)]
+    pub disabled: bool,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let folder = match &self.folder {
+            Some(f) => Some(
+                ctx.client
+                    .find_folder(&ctx.opts.session, f)
+                    .context(HttpClientSnafu)?
+                    .id,
+            ),
+            None => None,
+        };
+        let tags = super::resolve_tags(ctx, &self.tags).context(HttpClientSnafu)?;
+        let data = SourceTagIn {
+            source: Source {
+                id: "".into(),
+                abbrev: self.name.clone(),
+                description: self.description.clone(),
+                counter: 0,
+                enabled: !self.disabled,
+                priority: self.priority.to_value().into(),
+                folder,
+                file_filter: self.file_filter.clone(),
+                language: self.language.clone(),
+                attachments_only: self.attachments_only,
+                created: 0,
+            },
+            tags,
+        };
+        let result = ctx
+            .client
+            .create_source(&ctx.opts.session, &data)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
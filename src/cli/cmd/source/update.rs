@@ -0,0 +1,124 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context, SourcePriority};
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::SourceTagIn;
+use crate::http::Error as HttpError;
+
+/// Change properties of a source.
+///
+/// Only the given properties are changed, all others are kept.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The source to change, given by its id (can be abbreviated to a
+    /// prefix) or name.
+    #[arg(long)]
+    pub id: String,
+
+    /// Set a new name.
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Set a new description.
+    #[arg(long)]
+    pub description: Option<String>,
+
+    /// Change the priority of processing jobs.
+    #[arg(long, value_enum)]
+    pub priority: Option<SourcePriority>,
+
+    /// Put all uploaded files into this folder, given by its id (can
+    /// be abbreviated to a prefix) or name.
+    #[arg(long)]
+    pub folder: Option<String>,
+
+    /// Don't put uploaded files into a folder.
+    #[arg(long, conflicts_with = "folder")]
+    pub remove_folder: bool,
+
+    /// Set a new glob pattern for accepted files.
+    #[arg(long)]
+    pub file_filter: Option<String>,
+
+    /// Set a new language for uploaded documents.
+    #[arg(long)]
+    pub language: Option<String>,
+
+    /// Replace the tags of the source with these. Can be ids or
+    /// names.
+    #[arg(long = "tag")]
+    pub tags: Vec<String>,
+
+    /// Remove all tags from the source.
+    #[arg(long, conflicts_with = "tags")]
+    pub clear_tags: bool,
+
+    /// Whether to only process attachments of e-mails.
+    #[arg(long)]
+    pub attachments_only: Option<bool>,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let current = ctx
+            .client
+            .find_source(&ctx.opts.session, &self.id)
+            .context(HttpClientSnafu)?;
+        let mut source = current.source;
+
+        if let Some(name) = &self.name {
+            source.abbrev = name.clone();
+        }
+        if let Some(descr) = &self.description {
+            source.description = Some(descr.clone());
+        }
+        if let Some(prio) = &self.priority {
+            source.priority = prio.to_value().into();
+        }
+        if let Some(folder) = &self.folder {
+            let folder = ctx
+                .client
+                .find_folder(&ctx.opts.session, folder)
+                .context(HttpClientSnafu)?;
+            source.folder = Some(folder.id);
+        }
+        if self.remove_folder {
+            source.folder = None;
+        }
+        if let Some(filter) = &self.file_filter {
+            source.file_filter = Some(filter.clone());
+        }
+        if let Some(lang) = &self.language {
+            source.language = Some(lang.clone());
+        }
+        if let Some(flag) = self.attachments_only {
+            source.attachments_only = flag;
+        }
+        let tags = if self.clear_tags {
+            vec![]
+        } else if self.tags.is_empty() {
+            current.tags.items.into_iter().map(|t| t.id).collect()
+        } else {
+            super::resolve_tags(ctx, &self.tags).context(HttpClientSnafu)?
+        };
+
+        let result = ctx
+            .client
+            .update_source(&ctx.opts.session, &SourceTagIn { source, tags })
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
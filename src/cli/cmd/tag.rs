@@ -0,0 +1,58 @@
+pub mod add;
+pub mod delete;
+pub mod list;
+pub mod merge;
+pub mod update;
+
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+
+/// Manage tags.
+#[derive(Parser, std::fmt::Debug)]
+pub struct Input {
+    #[command(subcommand)]
+    pub subcmd: TagCommand,
+}
+
+#[derive(Parser, Debug)]
+pub enum TagCommand {
+    #[command(version)]
+    List(list::Input),
+
+    #[command(version)]
+    Add(add::Input),
+
+    #[command(version)]
+    Update(update::Input),
+
+    #[command(version)]
+    Delete(delete::Input),
+
+    #[command(version)]
+    Merge(merge::Input),
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    List { source: list::Error },
+    Add { source: add::Error },
+    Update { source: update::Error },
+    Delete { source: delete::Error },
+    Merge { source: merge::Error },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        match &self.subcmd {
+            TagCommand::List(input) => input.exec(ctx).context(ListSnafu),
+            TagCommand::Add(input) => input.exec(ctx).context(AddSnafu),
+            TagCommand::Update(input) => input.exec(ctx).context(UpdateSnafu),
+            TagCommand::Delete(input) => input.exec(ctx).context(DeleteSnafu),
+            TagCommand::Merge(input) => input.exec(ctx).context(MergeSnafu),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::Tag;
+use crate::http::Error as HttpError;
+
+/// Create a new tag.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The name of the new tag.
+    #[arg(long)]
+    pub name: String,
+
+    /// An optional category for the tag.
+    #[arg(long)]
+    pub category: Option<String>,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let tag = Tag {
+            id: "".into(),
+            name: self.name.clone(),
+            category: self.category.clone(),
+            created: 0,
+        };
+        let result = ctx
+            .client
+            .create_tag(&ctx.opts.session, &tag)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
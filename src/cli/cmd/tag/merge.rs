@@ -0,0 +1,144 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::payload::{BasicResult, SearchMode, SearchReq, StringList};
+use crate::http::Error as HttpError;
+
+/// Merge one tag into another.
+///
+/// All items tagged with the source tag get the target tag instead.
+/// Afterwards the source tag is deleted. If changing the tags of an
+/// item fails, the merge stops and the source tag is kept.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The tag to merge and delete, given by its id (can be
+    /// abbreviated to a prefix) or name.
+    #[arg(long)]
+    pub from: String,
+
+    /// The tag that replaces the source tag on all items, given by
+    /// its id (can be abbreviated to a prefix) or name.
+    #[arg(long)]
+    pub to: String,
+
+    /// How many items to fetch per search request.
+    #[arg(long, default_value = "100", value_parser = clap::value_parser!(u32).range(1..))]
+    pub batch_size: u32,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+
+    #[snafu(display("Cannot merge tag '{}' into itself", name))]
+    SameTag { name: String },
+
+    #[snafu(display("Changing the tags of item '{}' failed: {}", id, message))]
+    ChangeTags { id: String, message: String },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let from = ctx
+            .client
+            .find_tag(&ctx.opts.session, &self.from)
+            .context(HttpClientSnafu)?;
+        let to = ctx
+            .client
+            .find_tag(&ctx.opts.session, &self.to)
+            .context(HttpClientSnafu)?;
+        if from.id == to.id {
+            return Err(Error::SameTag { name: from.name });
+        }
+
+        let item_ids = find_tagged_items(&from.id, self.batch_size, ctx)?;
+        let add = StringList {
+            items: vec![to.id.clone()],
+        };
+        let remove = StringList {
+            items: vec![from.id.clone()],
+        };
+        for id in &item_ids {
+            let linked = ctx
+                .client
+                .link_tags(&ctx.opts.session, id, &add)
+                .context(HttpClientSnafu)?;
+            check_result(id, linked)?;
+            let removed = ctx
+                .client
+                .remove_tags(&ctx.opts.session, id, &remove)
+                .context(HttpClientSnafu)?;
+            check_result(id, removed)?;
+        }
+        let deleted = ctx
+            .client
+            .delete_tag(&ctx.opts.session, &from.id)
+            .context(HttpClientSnafu)?;
+        if !deleted.success {
+            ctx.write_result(deleted).context(WriteResultSnafu)?;
+            return Ok(());
+        }
+
+        let result = BasicResult {
+            success: true,
+            message: format!(
+                "Moved {} items from tag '{}' to '{}' and deleted '{}'.",
+                item_ids.len(),
+                from.name,
+                to.name,
+                from.name
+            ),
+        };
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
+
+/// Turns an unsuccessful result of changing the tags of an item into
+/// an error.
+fn check_result(id: &str, result: BasicResult) -> Result<(), Error> {
+    if result.success {
+        Ok(())
+    } else {
+        Err(Error::ChangeTags {
+            id: id.to_string(),
+            message: result.message,
+        })
+    }
+}
+
+/// Collects the ids of all items (including trashed ones) that are
+/// tagged with the given tag. All ids are fetched before any item is
+/// changed, so paging is not affected by the changes.
+fn find_tagged_items(tag_id: &str, batch_size: u32, ctx: &Context) -> Result<Vec<String>, Error> {
+    let mut req = SearchReq {
+        offset: 0,
+        limit: batch_size,
+        with_details: false,
+        query: format!("tag.id:{}", tag_id),
+        search_mode: SearchMode::All,
+    };
+    let mut ids = Vec::new();
+    loop {
+        let result = ctx
+            .client
+            .search(&ctx.opts.session, &req)
+            .context(HttpClientSnafu)?;
+        let count = ids.len();
+        for group in result.groups {
+            ids.extend(group.items.into_iter().map(|item| item.id));
+        }
+        if ids.len() - count < batch_size as usize {
+            return Ok(ids);
+        }
+        req.offset += batch_size;
+    }
+}
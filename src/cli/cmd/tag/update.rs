@@ -0,0 +1,66 @@
+use clap::Parser;
+use snafu::{ResultExt, Snafu};
+
+use super::{Cmd, Context};
+use crate::cli::sink::Error as SinkError;
+use crate::http::Error as HttpError;
+
+/// Rename a tag or change its category.
+///
+/// Only the given properties are changed, all others are kept.
+#[derive(Parser, Debug)]
+pub struct Input {
+    /// The tag to change, given by its id (can be abbreviated to a
+    /// prefix) or name.
+    #[arg(long)]
+    pub id: String,
+
+    /// Set a new name.
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Set a new category.
+    #[arg(long)]
+    pub category: Option<String>,
+
+    /// Remove the category from the tag.
+    #[arg(long, conflicts_with = "category")]
+    pub remove_category: bool,
+}
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("An http error occurred: {}", source))]
+    HttpClient { source: HttpError },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
+}
+
+impl Cmd for Input {
+    type CmdError = Error;
+
+    fn exec(&self, ctx: &Context) -> Result<(), Error> {
+        let mut tag = ctx
+            .client
+            .find_tag(&ctx.opts.session, &self.id)
+            .context(HttpClientSnafu)?;
+
+        if let Some(name) = &self.name {
+            tag.name = name.clone();
+        }
+        if let Some(category) = &self.category {
+            tag.category = Some(category.clone());
+        }
+        if self.remove_category {
+            tag.category = None;
+        }
+
+        let result = ctx
+            .client
+            .update_tag(&ctx.opts.session, &tag)
+            .context(HttpClientSnafu)?;
+        ctx.write_result(result).context(WriteResultSnafu)?;
+        Ok(())
+    }
+}
@@ -1,11 +1,12 @@
 use clap::{ArgAction, ArgGroup, Parser, ValueHint};
 use snafu::{ResultExt, Snafu};
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 use super::{Cmd, Context};
 use crate::cli::opts::{EndpointOpts, FileAction, FileAuthError, UploadMeta};
 use crate::cli::sink::Error as SinkError;
-use crate::http::payload::{BasicResult, StringList, UploadMeta as MetaRequest};
+use crate::http::payload::{BasicResult, ItemShort, StringList, UploadMeta as MetaRequest};
 use crate::http::{Error as HttpError, FileAuth};
 use crate::util::file::FileActionResult;
 use crate::util::{digest, file};
@@ -30,6 +31,12 @@ use crate::util::{digest, file};
 /// So using `upload --traverse --delete` will upload all files that
 /// are not yet in Docspell and then deletes them.
 ///
+/// With `--wait`, the command blocks until all uploaded files have
+/// been processed and prints the resulting items instead. Items are
+/// found by the checksum of each file. If the server doesn't create
+/// an item for a file (e.g. because processing failed), the command
+/// waits until the timeout is reached.
+///
 /// For glob patterns, see <https://docs.rs/glob/0.3.0/glob/struct.Pattern.html>
 #[derive(Parser, Debug)]
 #[command(group = ArgGroup::new("g_multiple"))]
@@ -79,6 +86,16 @@ pub struct Input {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Wait until all files have been processed and print the
+    /// resulting items.
+    #[arg(long, conflicts_with_all = ["poll", "dry_run"])]
+    pub wait: bool,
+
+    /// The maximum number of seconds to wait for processing when
+    /// using `--wait`.
+    #[arg(long, default_value = "300", requires = "wait")]
+    pub timeout: u64,
+
     /// One or more files to upload
     #[arg(required = true, num_args = 1, value_hint = ValueHint::FilePath)]
     pub files: Vec<PathBuf>,
@@ -134,6 +151,12 @@ pub enum Error {
     #[snafu(display("The `--poll` option requires `--traverse`"))]
     PollWithoutTraverse,
 
+    #[snafu(display("Uploading failed: {}", message))]
+    UploadFailed { message: String },
+
+    #[snafu(display("Timeout while waiting for {} file(s) to be processed", pending))]
+    WaitTimeout { pending: usize },
+
     #[snafu(display("The glob pattern '{}' is invalid: {}", pattern, source))]
     BadGlobPattern {
         source: glob::PatternError,
@@ -145,13 +168,40 @@ impl Cmd for Input {
     type CmdError = Error;
 
     fn exec(&self, ctx: &Context) -> Result<(), Error> {
-        let result = upload_files(self, ctx)?;
-        ctx.write_result(result).context(WriteResultSnafu)?;
+        if self.wait {
+            let items = upload_and_wait(self, ctx)?;
+            ctx.write_result(items).context(WriteResultSnafu)?;
+        } else {
+            let result = upload_files(self, ctx)?;
+            ctx.write_result(result).context(WriteResultSnafu)?;
+        }
         Ok(())
     }
 }
 
 pub fn upload_files(args: &Input, ctx: &Context) -> Result<BasicResult, Error> {
+    upload_collect(args, ctx, &mut Submitted::new(false))
+}
+
+/// Uploads the files and waits until all of them have been processed.
+/// Returns the items that have been created from the files or that
+/// already existed.
+pub fn upload_and_wait(args: &Input, ctx: &Context) -> Result<Vec<ItemShort>, Error> {
+    let mut submitted = Submitted::new(true);
+    let result = upload_collect(args, ctx, &mut submitted)?;
+    if !result.success {
+        return Err(Error::UploadFailed {
+            message: result.message,
+        });
+    }
+    wait_for_items(&submitted, args.timeout, ctx)
+}
+
+fn upload_collect(
+    args: &Input,
+    ctx: &Context,
+    submitted: &mut Submitted,
+) -> Result<BasicResult, Error> {
     check_flags(args)?;
     let matcher = matching::Matcher::new(args)?;
 
@@ -175,7 +225,7 @@ pub fn upload_files(args: &Input, ctx: &Context) -> Result<BasicResult, Error> {
     log::debug!("Send file metadata: {:?}", serde_json::to_string(&meta));
     if args.traverse {
         if let Some(delay) = args.poll {
-            let delay_dur = std::time::Duration::from_secs(delay);
+            let delay_dur = Duration::from_secs(delay);
             let dir_list = args
                 .files
                 .iter()
@@ -187,17 +237,65 @@ pub fn upload_files(args: &Input, ctx: &Context) -> Result<BasicResult, Error> {
                     "Traversing to upload '{}' (every {:?}) …",
                     dir_list, delay_dur
                 );
-                upload_traverse(&meta, args, ctx, &matcher)?;
+                upload_traverse(&meta, args, ctx, &matcher, submitted)?;
                 std::thread::sleep(delay_dur);
             }
         } else {
-            upload_traverse(&meta, args, ctx, &matcher)
+            upload_traverse(&meta, args, ctx, &matcher, submitted)
         }
     } else {
-        upload_single(&meta, args, ctx, matcher)
+        upload_single(&meta, args, ctx, matcher, submitted)
     }
 }
 
+/// Polls the server until items exist for all submitted files and
+/// processing has finished, or the timeout (in seconds) is reached.
+fn wait_for_items(
+    submitted: &Submitted,
+    timeout: u64,
+    ctx: &Context,
+) -> Result<Vec<ItemShort>, Error> {
+    let deadline = Instant::now() + Duration::from_secs(timeout);
+    let mut pending: Vec<&(String, FileAuth)> = submitted.files.iter().collect();
+    let mut items: Vec<ItemShort> = Vec::new();
+    if !pending.is_empty() {
+        eprintln!("Waiting for {} file(s) to be processed …", pending.len());
+    }
+    loop {
+        let mut still_pending = Vec::new();
+        for entry in pending {
+            let (hash, fauth) = entry;
+            let result = ctx
+                .client
+                .file_exists(hash.as_str(), fauth)
+                .context(HttpClientSnafu)?;
+            if result.exists && result.items.iter().all(is_processed) {
+                for item in result.items {
+                    if !items.iter().any(|i| i.id == item.id) {
+                        items.push(item);
+                    }
+                }
+            } else {
+                still_pending.push(entry);
+            }
+        }
+        pending = still_pending;
+        if pending.is_empty() {
+            return Ok(items);
+        }
+        if Instant::now() >= deadline {
+            return Err(Error::WaitTimeout {
+                pending: pending.len(),
+            });
+        }
+        std::thread::sleep(WAIT_POLL_INTERVAL);
+    }
+}
+
+fn is_processed(item: &ItemShort) -> bool {
+    !matches!(item.state.as_str(), "premature" | "processing")
+}
+
 fn apply_file_action(path: &Path, root: Option<&PathBuf>, opts: &Input) -> Result<(), Error> {
     let res = opts
         .action
@@ -221,6 +319,7 @@ fn upload_traverse(
     opts: &Input,
     ctx: &Context,
     matcher: &matching::Matcher,
+    submitted: &mut Submitted,
 ) -> Result<BasicResult, Error> {
     log::debug!("Upload by traversing directory");
     let mut counter = 0;
@@ -249,6 +348,7 @@ fn upload_traverse(
                         eprintln!("Uploading {}", child.display());
                         counter += 1;
                         if !opts.dry_run {
+                            submitted.add(&child, &fauth)?;
                             let res = ctx
                                 .client
                                 .upload_files(&fauth, meta, &[child.as_path()])
@@ -259,6 +359,7 @@ fn upload_traverse(
                         }
                     } else {
                         file_exists_message(&child);
+                        submitted.add(&child, &fauth)?;
                         apply_file_action(&child, Some(path), opts)?;
                     }
                 }
@@ -274,6 +375,7 @@ fn upload_traverse(
                 eprintln!("Uploading file {}", path.display());
                 counter += 1;
                 if !opts.dry_run {
+                    submitted.add(path, &fauth)?;
                     let res = ctx
                         .client
                         .upload_files(&fauth, meta, &[path.as_path()])
@@ -284,6 +386,7 @@ fn upload_traverse(
                 }
             } else {
                 file_exists_message(path);
+                submitted.add(path, &fauth)?;
                 apply_file_action(path, None, opts)?;
             }
         }
@@ -305,6 +408,7 @@ fn upload_single(
     opts: &Input,
     ctx: &Context,
     matcher: matching::Matcher,
+    submitted: &mut Submitted,
 ) -> Result<BasicResult, Error> {
     log::debug!("Upload using a single request");
     let mut files: Vec<&Path> = Vec::new();
@@ -335,10 +439,12 @@ fn upload_single(
             if !exists {
                 eprintln!("Adding to single request: {}", path.display());
                 if !opts.dry_run {
+                    submitted.add(path, &fauth)?;
                     files.push(path);
                 }
             } else {
                 file_exists_message(path);
+                submitted.add(path, &fauth)?;
                 apply_file_action(path, None, opts)?;
             }
         } else {
@@ -412,6 +518,32 @@ fn check_flags(args: &Input) -> Result<(), Error> {
 //////////////////////////////////////////////////////////////////////////////
 /// Helper types
 
+const WAIT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Collects the checksums of submitted files, so it is possible to
+/// wait for their items. Nothing is collected if it is not enabled.
+struct Submitted {
+    enabled: bool,
+    files: Vec<(String, FileAuth)>,
+}
+
+impl Submitted {
+    fn new(enabled: bool) -> Submitted {
+        Submitted {
+            enabled,
+            files: Vec::new(),
+        }
+    }
+
+    fn add(&mut self, path: &Path, fauth: &FileAuth) -> Result<(), Error> {
+        if self.enabled {
+            let hash = digest::digest_file_sha256(path).context(DigestFileSnafu { path })?;
+            self.files.push((hash, fauth.clone()));
+        }
+        Ok(())
+    }
+}
+
 mod matching {
     use super::*;
     use glob::{GlobResult, Paths, Pattern};
@@ -511,3 +643,27 @@ mod matching {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(state: &str) -> ItemShort {
+        ItemShort {
+            id: "abc".into(),
+            name: "test.pdf".into(),
+            direction: "incoming".into(),
+            state: state.into(),
+            created: 0,
+            item_date: None,
+        }
+    }
+
+    #[test]
+    fn unit_is_processed() {
+        assert!(is_processed(&item("created")));
+        assert!(is_processed(&item("confirmed")));
+        assert!(!is_processed(&item("premature")));
+        assert!(!is_processed(&item("processing")));
+    }
+}
@@ -9,8 +9,8 @@ use std::{
 use super::{Cmd, Context};
 use crate::cli::opts::SearchMode;
 use crate::http::payload::SearchReq;
-use crate::http::DownloadRef;
 use crate::http::Error as HttpError;
+use crate::http::{DownloadAuth, DownloadRef};
 
 /// View pdf files.
 ///
@@ -84,7 +84,7 @@ pub fn view_all(opts: &Input, ctx: &Context, parent: &Path) -> Result<(), Error>
     };
     let result = ctx
         .client
-        .download_search(&ctx.opts.session, &req)
+        .download_search(&DownloadAuth::from_session(&ctx.opts.session), &req)
         .context(HttpClientSnafu)?;
 
     let mut confirm = false;
@@ -146,7 +146,7 @@ fn is_stop_viewing(opts: &Input) -> Result<bool, Error> {
 
 fn download(attach: &DownloadRef, ctx: &Context, parent: &Path) -> Result<Option<PathBuf>, Error> {
     let dlopt = attach
-        .get(&ctx.client, &ctx.opts.session)
+        .get(&ctx.client, &DownloadAuth::from_session(&ctx.opts.session))
         .context(HttpClientSnafu)?;
 
     let path = parent.join("view.pdf");
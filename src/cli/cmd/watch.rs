@@ -5,6 +5,7 @@ use std::{path::Path, sync::mpsc};
 use std::{path::PathBuf, time::Duration};
 
 use super::{upload, Cmd, Context};
+use crate::cli::sink::Error as SinkError;
 use crate::http::payload::BasicResult;
 use crate::{
     cli::opts::{EndpointOpts, FileAction, UploadMeta},
@@ -25,6 +26,10 @@ use crate::util::file;
 /// On some filesystems, this command may not work (e.g. networking
 /// file systems like NFS or SAMBA). You may use the `upload` command
 /// then in combination with the `--poll` option.
+///
+/// With `--wait`, each file is waited for until it has been processed
+/// and the resulting items are printed. If no item is created for a
+/// file, the next file is handled only after the timeout.
 #[derive(Parser, Debug)]
 pub struct Input {
     /// Wether to watch directories recursively or not.
@@ -55,6 +60,16 @@ pub struct Input {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Wait until each file has been processed and print the
+    /// resulting items.
+    #[arg(long, conflicts_with = "dry_run")]
+    pub wait: bool,
+
+    /// The maximum number of seconds to wait for processing of a
+    /// file when using `--wait`.
+    #[arg(long, default_value = "300", requires = "wait")]
+    pub timeout: u64,
+
     #[clap(flatten)]
     pub endpoint: EndpointOpts,
 
@@ -88,6 +103,9 @@ pub enum Error {
 
     #[snafu(display("Could not find a collective for {}", path.display()))]
     NoCollective { path: PathBuf },
+
+    #[snafu(display("Error writing data: {}", source))]
+    WriteResult { source: SinkError },
 }
 
 impl Cmd for Input {
@@ -156,6 +174,9 @@ fn upload_and_report(path: PathBuf, opts: &Input, ctx: &Context) -> Result<(), E
     } else {
         eprintln!("------------------------------------------------------------------------------");
         eprintln!("Got: {}", path.display());
+        if opts.wait {
+            return upload_and_wait(path, opts, ctx);
+        }
         let result = upload_file(path, opts, ctx)?;
         if result.success {
             if opts.dry_run {
@@ -171,13 +192,35 @@ fn upload_and_report(path: PathBuf, opts: &Input, ctx: &Context) -> Result<(), E
     Ok(())
 }
 
+fn upload_and_wait(path: PathBuf, opts: &Input, ctx: &Context) -> Result<(), Error> {
+    let data = &upload_input(path, opts)?;
+    match upload::upload_and_wait(data, ctx) {
+        Ok(items) => ctx.write_result(items).context(WriteResultSnafu),
+        Err(upload::Error::UploadFailed { message }) => {
+            log::error!("Error from uploading: {}", message);
+            eprintln!("Server Error: {}", message);
+            Ok(())
+        }
+        Err(upload::Error::WaitTimeout { pending }) => {
+            eprintln!("Timeout waiting for {} file(s) to be processed.", pending);
+            Ok(())
+        }
+        Err(err) => Err(Error::Upload { source: err }),
+    }
+}
+
 fn upload_file(path: PathBuf, opts: &Input, ctx: &Context) -> Result<BasicResult, Error> {
+    let data = &upload_input(path, opts)?;
+    upload::upload_files(data, ctx).context(UploadSnafu)
+}
+
+fn upload_input(path: PathBuf, opts: &Input) -> Result<upload::Input, Error> {
     let mut ep = opts.endpoint.clone();
     if let Some(cid) = find_collective(&path, &opts.dirs, &opts.endpoint)? {
         ep.collective = Some(cid);
     }
 
-    let data = &upload::Input {
+    Ok(upload::Input {
         endpoint: ep,
         multiple: true,
         action: opts.action.clone(),
@@ -187,9 +230,10 @@ fn upload_file(path: PathBuf, opts: &Input, ctx: &Context) -> Result<BasicResult
         traverse: false,
         poll: None,
         dry_run: opts.dry_run,
+        wait: opts.wait,
+        timeout: opts.timeout,
         files: vec![path],
-    };
-    upload::upload_files(data, ctx).context(UploadSnafu)
+    })
 }
 
 pub fn find_collective(
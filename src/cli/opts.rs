@@ -5,8 +5,9 @@ use crate::{
     config::DsConfig,
     http::payload,
     http::proxy,
-    http::{FileAuth, IntegrationAuth, IntegrationData},
+    http::{Client, Error as HttpError, FileAuth, IntegrationAuth, IntegrationData},
 };
+use chrono::{NaiveDate, TimeZone, Utc};
 use clap::{ArgAction, ArgGroup, Parser, ValueEnum, ValueHint};
 use serde::{Deserialize, Serialize};
 use snafu::Snafu;
@@ -206,6 +207,45 @@ pub enum SubCommand {
 
     #[command(version)]
     OpenItem(open_item::Input),
+
+    #[command(version, alias = "organization")]
+    Org(org::Input),
+
+    #[command(version)]
+    Person(person::Input),
+
+    #[command(version, alias = "equip")]
+    Equipment(equipment::Input),
+
+    #[command(version)]
+    Folder(folder::Input),
+
+    #[command(version)]
+    Field(field::Input),
+
+    #[command(version)]
+    Tag(tag::Input),
+
+    #[command(version, alias = "attach")]
+    Attachment(attachment::Input),
+
+    #[command(version)]
+    Queue(queue::Input),
+
+    #[command(version)]
+    Share(share::Input),
+
+    #[command(version)]
+    Notify(notify::Input),
+
+    #[command(version)]
+    PeriodicQuery(periodic_query::Input),
+
+    #[command(version)]
+    MailSettings(mail_settings::Input),
+
+    #[command(version)]
+    ScanMailbox(scan_mailbox::Input),
 }
 
 /// The format for presenting the results.
@@ -395,7 +435,7 @@ impl Direction {
     pub fn to_value(&self) -> &'static str {
         match self {
             Direction::In => "incoming",
-            Direction::Out => "outgoung",
+            Direction::Out => "outgoing",
         }
     }
 }
@@ -479,3 +519,161 @@ impl SearchMode {
         }
     }
 }
+
+// Shared options for selecting one or more items.
+#[derive(Parser, Debug, Clone)]
+#[command(group = ArgGroup::new("items").required(true))]
+pub struct ItemSelect {
+    /// One or more item ids (can be abbreviated to a prefix).
+    #[arg(group = "items")]
+    pub ids: Vec<String>,
+
+    /// Select all items matching this query. See
+    /// <https://docspell.org/docs/query/>
+    #[arg(long, short, group = "items")]
+    pub query: Option<String>,
+}
+
+impl ItemSelect {
+    /// Returns the selected item ids. Ids given directly are returned
+    /// as is, otherwise the query is run using the given search mode.
+    pub fn resolve_ids(
+        &self,
+        client: &Client,
+        token: &Option<String>,
+        search_mode: payload::SearchMode,
+    ) -> Result<Vec<String>, HttpError> {
+        match &self.query {
+            Some(q) => client.search_ids(token, q, search_mode),
+            None => Ok(self.ids.clone()),
+        }
+    }
+}
+
+// Shared options for the address and contacts of organizations and
+// persons.
+#[derive(Parser, Debug, Clone)]
+pub struct ContactOpts {
+    /// The street, including the house number.
+    #[arg(long)]
+    pub street: Option<String>,
+
+    /// The zip code.
+    #[arg(long)]
+    pub zip: Option<String>,
+
+    /// The city.
+    #[arg(long)]
+    pub city: Option<String>,
+
+    /// The country.
+    #[arg(long)]
+    pub country: Option<String>,
+
+    /// A contact given as `kind:value` pair, where kind is one of
+    /// `phone`, `mobile`, `fax`, `email` or `website`. The option can
+    /// be repeated. When updating, the given contacts replace all
+    /// existing ones.
+    #[arg(long = "contact", required = false, num_args = 1)]
+    pub contacts: Vec<ContactArg>,
+}
+
+impl ContactOpts {
+    /// Sets all given address parts, leaving the others untouched.
+    pub fn update_address(&self, address: &mut payload::Address) {
+        if let Some(street) = &self.street {
+            address.street = street.clone();
+        }
+        if let Some(zip) = &self.zip {
+            address.zip = zip.clone();
+        }
+        if let Some(city) = &self.city {
+            address.city = city.clone();
+        }
+        if let Some(country) = &self.country {
+            address.country = country.clone();
+        }
+    }
+
+    /// Returns the given contacts or `None` if no contact was given.
+    pub fn to_contacts(&self) -> Option<Vec<payload::Contact>> {
+        if self.contacts.is_empty() {
+            None
+        } else {
+            Some(
+                self.contacts
+                    .iter()
+                    .map(|c| payload::Contact {
+                        id: "".into(),
+                        value: c.value.clone(),
+                        kind: c.kind.clone(),
+                    })
+                    .collect(),
+            )
+        }
+    }
+}
+
+/// A contact of some kind, given as `kind:value` pair.
+#[derive(Debug, Clone)]
+pub struct ContactArg {
+    pub kind: String,
+    pub value: String,
+}
+
+impl FromStr for ContactArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let nv = NameVal::from_str(s)?;
+        let kind = nv.name.trim().to_lowercase();
+        match kind.as_str() {
+            "phone" | "mobile" | "fax" | "email" | "website" => Ok(ContactArg {
+                kind,
+                value: nv.value.trim().into(),
+            }),
+            _ => Err(format!(
+                "Unknown contact kind '{}', use one of: phone, mobile, fax, email, website",
+                nv.name
+            )),
+        }
+    }
+}
+
+/// Parses a date given as `YYYY-MM-DD` into a unix timestamp in
+/// milliseconds at midnight UTC.
+pub fn parse_date(s: &str) -> Result<i64, String> {
+    let date = NaiveDate::parse_from_str(s, "%Y-%m-%d").map_err(|e| e.to_string())?;
+    let dt = date.and_hms_opt(0, 0, 0).ok_or("Invalid date")?;
+    Ok(Utc.from_utc_datetime(&dt).timestamp_millis())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_contact_arg_from_str() {
+        let c = ContactArg::from_str("Email: me@example.com ").unwrap();
+        assert_eq!(c.kind, "email");
+        assert_eq!(c.value, "me@example.com");
+
+        let c = ContactArg::from_str("website:https://example.com").unwrap();
+        assert_eq!(c.kind, "website");
+        assert_eq!(c.value, "https://example.com");
+    }
+
+    #[test]
+    fn unit_parse_date() {
+        assert_eq!(parse_date("2021-03-04"), Ok(1614816000000));
+        assert_eq!(parse_date("1970-01-01"), Ok(0));
+        assert!(parse_date("2021-02-30").is_err());
+        assert!(parse_date("04.03.2021").is_err());
+    }
+
+    #[test]
+    fn unit_contact_arg_from_str_invalid() {
+        assert!(ContactArg::from_str("pager:1234").is_err());
+        assert!(ContactArg::from_str("me@example.com").is_err());
+    }
+}
@@ -69,6 +69,26 @@ fn combine(opta: &Option<IdName>, optb: &Option<IdName>, sep: &str) -> String {
     }
 }
 
+/// Formats an address into a single line, skipping empty parts.
+fn format_address(addr: &Address) -> String {
+    let city = format!("{} {}", addr.zip, addr.city);
+    [addr.street.trim(), city.trim(), addr.country.trim()]
+        .iter()
+        .filter(|s| !s.is_empty())
+        .copied()
+        .collect::<Vec<&str>>()
+        .join(", ")
+}
+
+/// Formats a list of contacts into a single line.
+fn format_contacts(contacts: &[Contact]) -> String {
+    contacts
+        .iter()
+        .map(|c| format!("{}: {}", c.kind, c.value))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
 /// Returns the value in the option or the empty string.
 pub fn str_or_empty(opt: Option<&String>) -> &str {
     opt.as_ref().map(|s| s.as_str()).unwrap_or("")
@@ -239,6 +259,448 @@ impl AsTable for Vec<SourceAndTags> {
 }
 impl Sink for Vec<SourceAndTags> {}
 
+impl AsTable for Vec<Tag> {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![bFg => "id", "name", "category", "created"]);
+        for tag in self {
+            table.add_row(row![
+                tag.id[0..8],
+                tag.name,
+                str_or_empty(tag.category.as_ref()),
+                format_date(tag.created),
+            ]);
+        }
+        table
+    }
+}
+impl Sink for Vec<Tag> {}
+
+impl AsTable for Vec<Organization> {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![bFg => "id", "name", "short name", "address", "contacts", "use"]);
+        for org in self {
+            table.add_row(row![
+                org.id[0..8],
+                org.name,
+                str_or_empty(org.short_name.as_ref()),
+                format_address(&org.address),
+                format_contacts(&org.contacts),
+                org.org_use,
+            ]);
+        }
+        table
+    }
+}
+impl Sink for Vec<Organization> {}
+
+impl AsTable for Vec<Person> {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![bFg => "id", "name", "organization", "address", "contacts", "use"]);
+        for person in self {
+            table.add_row(row![
+                person.id[0..8],
+                person.name,
+                str_or_empty(person.organization.as_ref().map(|o| &o.name)),
+                format_address(&person.address),
+                format_contacts(&person.contacts),
+                person.person_use,
+            ]);
+        }
+        table
+    }
+}
+impl Sink for Vec<Person> {}
+
+impl AsTable for Vec<Equipment> {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![bFg => "id", "name", "use", "created", "notes"]);
+        for equip in self {
+            table.add_row(row![
+                equip.id[0..8],
+                equip.name,
+                equip.equip_use,
+                format_date(equip.created),
+                str_or_empty(equip.notes.as_ref()),
+            ]);
+        }
+        table
+    }
+}
+impl Sink for Vec<Equipment> {}
+
+impl AsTable for Vec<CustomFieldDef> {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![bFg => "id", "name", "label", "type", "usages", "created"]);
+        for field in self {
+            table.add_row(row![
+                field.id[0..8],
+                field.name,
+                str_or_empty(field.label.as_ref()),
+                field.ftype,
+                field.usages,
+                format_date(field.created),
+            ]);
+        }
+        table
+    }
+}
+impl Sink for Vec<CustomFieldDef> {}
+
+impl AsTable for Vec<FolderItem> {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![bFg => "id", "name", "owner", "members", "member", "created"]);
+        for folder in self {
+            table.add_row(row![
+                folder.id[0..8],
+                folder.name,
+                folder.owner.name,
+                folder.member_count,
+                folder.is_member,
+                format_date(folder.created),
+            ]);
+        }
+        table
+    }
+}
+impl Sink for Vec<FolderItem> {}
+
+impl AsTable for FolderDetail {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![bFg => "id", "member"]);
+        for member in &self.members {
+            table.add_row(row![member.id[0..8], member.name]);
+        }
+        table
+    }
+}
+impl Sink for FolderDetail {}
+
+impl AsTable for AttachmentMeta {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![bFg => "Property", "Value"]);
+        table.add_row(row!["Language", str_or_empty(self.language.as_ref())]);
+        table.add_row(row![
+            "Pages",
+            self.pages.map(|n| n.to_string()).unwrap_or_default()
+        ]);
+        let labels: Vec<String> = self
+            .labels
+            .iter()
+            .map(|l| format!("{} ({})", l.label, l.tag))
+            .collect();
+        table.add_row(row!["Labels", labels.join(", ")]);
+        table.add_row(row!["Content", self.content]);
+        table
+    }
+}
+impl Sink for AttachmentMeta {}
+
+impl AsTable for Vec<AttachmentText> {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![bFg => "id", "name", "content"]);
+        for text in self {
+            table.add_row(row![
+                text.id[0..8],
+                str_or_empty(text.name.as_ref()),
+                text.content,
+            ]);
+        }
+        table
+    }
+}
+impl Sink for Vec<AttachmentText> {}
+
+impl AsTable for ItemProposals {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![bFg => "kind", "id", "value"]);
+        let names = [
+            ("correspondent org", &self.corr_org),
+            ("correspondent person", &self.corr_person),
+            ("concerning person", &self.conc_person),
+            ("concerning equipment", &self.conc_equipment),
+        ];
+        for (kind, proposals) in names {
+            for p in proposals {
+                table.add_row(row![kind, p.id[0..8], p.name]);
+            }
+        }
+        let dates = [("date", &self.item_date), ("due date", &self.due_date)];
+        for (kind, proposals) in dates {
+            for dt in proposals {
+                table.add_row(row![kind, "", format_date(*dt)]);
+            }
+        }
+        table
+    }
+}
+impl Sink for ItemProposals {}
+
+impl AsTable for JobQueueState {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![
+            bFg => "id", "name", "state", "prio", "progress", "retries", "submitted", "started", "finished", "worker"
+        ]);
+        let jobs = self
+            .progress
+            .iter()
+            .chain(self.queued.iter())
+            .chain(self.completed.iter());
+        for job in jobs {
+            table.add_row(row![
+                job.id[0..8],
+                job.name,
+                job.state,
+                job.priority,
+                job.progress.map(|p| format!("{}%", p)).unwrap_or_default(),
+                job.retries,
+                format_date_by(job.submitted, "%Y-%m-%d %H:%M"),
+                job.started
+                    .map(|dt| format_date_by(dt, "%Y-%m-%d %H:%M"))
+                    .unwrap_or_default(),
+                job.finished
+                    .map(|dt| format_date_by(dt, "%Y-%m-%d %H:%M"))
+                    .unwrap_or_default(),
+                str_or_empty(job.worker.as_ref()),
+            ]);
+        }
+        table
+    }
+}
+impl Sink for JobQueueState {}
+
+impl AsTable for ShareList {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![bFg =>
+            "id",
+            "name",
+            "enabled",
+            "password",
+            "publish until",
+            "expired",
+            "views",
+            "owner",
+        ]);
+        for share in &self.items {
+            table.add_row(row![
+                share.id[0..8],
+                str_or_empty(share.name.as_ref()),
+                share.enabled,
+                share.password,
+                format_date(share.publish_until),
+                share.expired,
+                share.views,
+                share.owner.name,
+            ]);
+        }
+        table
+    }
+}
+impl Sink for ShareList {}
+
+impl AsTable for Vec<NotificationChannel> {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![bFg => "id", "name", "type", "target"]);
+        for channel in self {
+            let target = match channel {
+                NotificationChannel::Mail(c) => c.recipients.join(", "),
+                NotificationChannel::Gotify(c) => c.url.clone(),
+                NotificationChannel::Matrix(c) => format!("{} ({})", c.room_id, c.home_server),
+                NotificationChannel::Http(c) => c.url.clone(),
+            };
+            table.add_row(row![
+                channel.id()[0..8],
+                str_or_empty(channel.name()),
+                channel.channel_type(),
+                target,
+            ]);
+        }
+        table
+    }
+}
+impl Sink for Vec<NotificationChannel> {}
+
+impl AsTable for Vec<NotificationHook> {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![bFg => "id", "enabled", "channels", "events", "filter"]);
+        for hook in self {
+            let channels: Vec<&str> = hook
+                .channels
+                .iter()
+                .map(|c| c.name.as_deref().unwrap_or(&c.id))
+                .collect();
+            let events = if hook.all_events {
+                String::from("(all)")
+            } else {
+                hook.events.join(", ")
+            };
+            table.add_row(row![
+                hook.id[0..8],
+                hook.enabled,
+                channels.join(", "),
+                events,
+                str_or_empty(hook.event_filter.as_ref()),
+            ]);
+        }
+        table
+    }
+}
+impl Sink for Vec<NotificationHook> {}
+
+impl AsTable for NotificationChannelTestResult {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![bFg => "success", "messages"]);
+        table.add_row(row![self.success, self.messages.join("\n")]);
+        table
+    }
+}
+impl Sink for NotificationChannelTestResult {}
+
+impl AsTable for Vec<PeriodicQuerySettings> {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![bFg =>
+            "id",
+            "enabled",
+            "summary",
+            "schedule",
+            "query",
+            "bookmark",
+            "channels",
+        ]);
+        for task in self {
+            let channels: Vec<&str> = task
+                .channels
+                .iter()
+                .map(|c| c.name.as_deref().unwrap_or(&c.id))
+                .collect();
+            table.add_row(row![
+                task.id[0..8],
+                task.enabled,
+                str_or_empty(task.summary.as_ref()),
+                task.schedule,
+                str_or_empty(task.query.as_ref()),
+                str_or_empty(task.bookmark.as_ref()),
+                channels.join(", "),
+            ]);
+        }
+        table
+    }
+}
+impl Sink for Vec<PeriodicQuerySettings> {}
+
+impl AsTable for EmailSettingsList {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![bFg => "name", "host", "port", "user", "from", "ssl"]);
+        for s in &self.items {
+            table.add_row(row![
+                s.name,
+                s.smtp_host,
+                s.smtp_port.map(|p| p.to_string()).unwrap_or_default(),
+                str_or_empty(s.smtp_user.as_ref()),
+                s.from,
+                s.ssl_type,
+            ]);
+        }
+        table
+    }
+}
+impl Sink for EmailSettingsList {}
+
+impl AsTable for ImapSettingsList {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![bFg => "name", "host", "port", "user", "ssl", "oauth"]);
+        for s in &self.items {
+            table.add_row(row![
+                s.name,
+                s.imap_host,
+                s.imap_port.map(|p| p.to_string()).unwrap_or_default(),
+                str_or_empty(s.imap_user.as_ref()),
+                s.ssl_type,
+                s.use_oauth,
+            ]);
+        }
+        table
+    }
+}
+impl Sink for ImapSettingsList {}
+
+impl AsTable for Vec<ScanMailboxSettings> {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![bFg =>
+            "id",
+            "enabled",
+            "summary",
+            "connection",
+            "schedule",
+            "folders",
+            "target folder",
+            "delete",
+        ]);
+        for task in self {
+            table.add_row(row![
+                task.id[0..8],
+                task.enabled,
+                str_or_empty(task.summary.as_ref()),
+                task.imap_connection,
+                task.schedule,
+                task.folders.join(", "),
+                str_or_empty(task.target_folder.as_ref()),
+                task.delete_mail,
+            ]);
+        }
+        table
+    }
+}
+impl Sink for Vec<ScanMailboxSettings> {}
+
+impl AsTable for SentMails {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table
+            .set_titles(row![bFg => "id", "sender", "connection", "recipients", "subject", "sent"]);
+        for mail in &self.items {
+            table.add_row(row![
+                mail.id[0..8],
+                mail.sender,
+                mail.connection,
+                mail.recipients.join(", "),
+                mail.subject,
+                format_date(mail.created),
+            ]);
+        }
+        table
+    }
+}
+impl Sink for SentMails {}
+
+impl AsTable for IdResult {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![bFg => "success", "id", "message"]);
+        table.add_row(row![self.success, self.id, self.message]);
+        table
+    }
+}
+impl Sink for IdResult {}
+
 impl AsTable for Vec<CheckFileResult> {
     fn to_table(&self) -> Table {
         let mut table = mk_table();
@@ -256,6 +718,23 @@ impl AsTable for Vec<CheckFileResult> {
 }
 impl Sink for Vec<CheckFileResult> {}
 
+impl AsTable for Vec<ItemShort> {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![bFg => "id", "name", "state", "created"]);
+        for item in self {
+            table.add_row(row![
+                item.id[0..8],
+                item.name,
+                item.state,
+                format_date(item.created),
+            ]);
+        }
+        table
+    }
+}
+impl Sink for Vec<ItemShort> {}
+
 impl AsTable for BasicResult {
     fn to_table(&self) -> Table {
         let mut table = mk_table();
@@ -269,6 +748,21 @@ impl AsTable for BasicResult {
 }
 impl Sink for BasicResult {}
 
+impl AsTable for Vec<BasicResult> {
+    fn to_table(&self) -> Table {
+        let mut table = mk_table();
+        table.set_titles(row![bFg =>
+            "success",
+            "message",
+        ]);
+        for result in self {
+            table.add_row(row![result.success, result.message,]);
+        }
+        table
+    }
+}
+impl Sink for Vec<BasicResult> {}
+
 impl AsTable for VersionInfo {
     fn to_table(&self) -> Table {
         let mut table = mk_table();
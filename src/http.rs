@@ -34,6 +34,11 @@
 //! endpoint](https://docspell.org/docs/api/upload/#integration-endpoint)
 //! can be used.
 //!
+//! Files of a [public
+//! share](https://docspell.org/docs/webapp/share/) can be downloaded
+//! without an account, using a token that is obtained by verifying
+//! the share id and its password.
+//!
 //! # Admin
 //!
 //! There are some commands that require the [admin
@@ -52,13 +57,14 @@ use std::{
 };
 
 use self::payload::*;
-use self::util::{DOCSPELL_ADMIN, DOCSPELL_AUTH};
+use self::util::{DOCSPELL_ADMIN, DOCSPELL_AUTH, DOCSPELL_SHARE_AUTH};
 use reqwest::blocking::{
     multipart::{Form, Part},
     ClientBuilder, RequestBuilder, Response,
 };
-use reqwest::header::CONTENT_DISPOSITION;
+use reqwest::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
 use reqwest::{Certificate, StatusCode};
+use serde::Serialize;
 use snafu::{ResultExt, Snafu};
 
 const APP_JSON: &str = "application/json";
@@ -106,6 +112,15 @@ pub enum Error {
 
     #[snafu(display("Item id not unique: {}", id))]
     ItemNotUnique { id: String },
+
+    #[snafu(display("No {} found for: {}", kind, key))]
+    NotFound { kind: String, key: String },
+
+    #[snafu(display("The {} is not unique: {}", kind, key))]
+    NotUnique { kind: String, key: String },
+
+    #[snafu(display("The {} of an attachment is not available via a share", what))]
+    ShareUnsupported { what: String },
 }
 
 /// The docspell http client.
@@ -294,158 +309,2075 @@ impl Client {
             .context(SerializeRespSnafu)
     }
 
-    /// Returns a summary for a given search query.
-    ///
-    /// If `token` is specified, it is used to authenticate. Otherwise
-    /// a stored session is used.
-    pub fn summary<S: Into<String>>(
+    /// Searches for documents using the given authentication. With a
+    /// share token, only documents of the public share are searched.
+    pub fn search_with(&self, auth: &DownloadAuth, req: &SearchReq) -> Result<SearchResult, Error> {
+        match auth {
+            DownloadAuth::Session { token } => self.search(token, req),
+            DownloadAuth::Share { token } => self.share_search(token, req),
+        }
+    }
+
+    /// Verifies the id and password of a public share. On success,
+    /// the result contains a token to access the share.
+    pub fn verify_share(
+        &self,
+        share_id: &str,
+        password: &Option<String>,
+    ) -> Result<ShareVerifyResult, Error> {
+        let url = &format!("{}/api/v1/open/share/verify", self.base_url);
+        let secret = ShareSecret {
+            share_id: share_id.to_string(),
+            password: password.clone(),
+        };
+        self.client
+            .post(url)
+            .json(&secret)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<ShareVerifyResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Searches for documents of a public share. The query is
+    /// combined with the query of the share. The `share_token` is
+    /// obtained via `verify_share`.
+    pub fn share_search(&self, share_token: &str, req: &SearchReq) -> Result<SearchResult, Error> {
+        let url = &format!("{}/api/v1/share/search/query", self.base_url);
+        self.client
+            .get(url)
+            .header(DOCSPELL_SHARE_AUTH, share_token)
+            .query(&[
+                ("limit", &req.limit.to_string()),
+                ("offset", &req.offset.to_string()),
+                ("withDetails", &req.with_details.to_string()),
+                ("q", &req.query),
+                ("searchMode", &req.search_mode.as_str().to_string()),
+            ])
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<SearchResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Returns the ids of all items matching the given query. The
+    /// results are fetched in batches, so this can be used for large
+    /// result sets.
+    pub fn search_ids(
+        &self,
+        token: &Option<String>,
+        query: &str,
+        search_mode: SearchMode,
+    ) -> Result<Vec<String>, Error> {
+        let batch_size = 100;
+        let mut req = SearchReq {
+            offset: 0,
+            limit: batch_size,
+            with_details: false,
+            query: query.to_string(),
+            search_mode,
+        };
+        let mut ids = Vec::new();
+        loop {
+            let result = self.search(token, &req)?;
+            let count = ids.len();
+            for group in result.groups {
+                ids.extend(group.items.into_iter().map(|item| item.id));
+            }
+            if ids.len() - count < batch_size as usize {
+                return Ok(ids);
+            }
+            req.offset += batch_size;
+        }
+    }
+
+    /// Returns a summary for a given search query.
+    ///
+    /// If `token` is specified, it is used to authenticate. Otherwise
+    /// a stored session is used.
+    pub fn summary<S: Into<String>>(
+        &self,
+        token: &Option<String>,
+        query: S,
+    ) -> Result<Summary, Error> {
+        let url = &format!("{}/api/v1/sec/item/searchStats", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .get(url)
+            .header(DOCSPELL_AUTH, token)
+            .query(&[("q", &query.into())])
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<Summary>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Lists all sources for the current user.
+    ///
+    /// If `token` is specified, it is used to authenticate. Otherwise
+    /// a stored session is used.
+    pub fn list_sources(&self, token: &Option<String>) -> Result<SourceList, Error> {
+        let url = &format!("{}/api/v1/sec/source", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .get(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<SourceList>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Finds a source by its id, its name or a prefix of its id.
+    pub fn find_source(
+        &self,
+        token: &Option<String>,
+        id_or_name: &str,
+    ) -> Result<SourceAndTags, Error> {
+        let sources = self.list_sources(token)?;
+        find_unique("source", id_or_name, sources.items, |s| {
+            (s.source.id.as_str(), s.source.abbrev.as_str())
+        })
+    }
+
+    /// Creates a new source. The `id`, `counter` and `created`
+    /// properties are ignored.
+    pub fn create_source(
+        &self,
+        token: &Option<String>,
+        source: &SourceTagIn,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/source", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(source)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Replaces the source with the same id with the given data. The
+    /// tags of the source are replaced with the given ones.
+    pub fn update_source(
+        &self,
+        token: &Option<String>,
+        source: &SourceTagIn,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/source", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .put(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(source)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Deletes the source with the given id.
+    pub fn delete_source(&self, token: &Option<String>, id: &str) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/source/{}", self.base_url, id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .delete(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Lists all tags. The `query` argument may be a query for a
+    /// name, which can contain the `*` wildcard at beginning or end.
+    pub fn list_tags(&self, token: &Option<String>, query: &str) -> Result<TagList, Error> {
+        let url = &format!("{}/api/v1/sec/tag", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .get(url)
+            .header(DOCSPELL_AUTH, token)
+            .query(&[("q", query)])
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<TagList>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Finds a tag by its id, its name or a prefix of its id.
+    pub fn find_tag(&self, token: &Option<String>, id_or_name: &str) -> Result<Tag, Error> {
+        let tags = self.list_tags(token, "")?;
+        find_unique("tag", id_or_name, tags.items, |t| {
+            (t.id.as_str(), t.name.as_str())
+        })
+    }
+
+    /// Creates a new tag. The `id` and `created` properties are
+    /// ignored.
+    pub fn create_tag(&self, token: &Option<String>, tag: &Tag) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/tag", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(tag)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Replaces the tag with the same id with the given data.
+    pub fn update_tag(&self, token: &Option<String>, tag: &Tag) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/tag", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .put(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(tag)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Deletes the tag with the given id. The tag is removed from all
+    /// items.
+    pub fn delete_tag(&self, token: &Option<String>, id: &str) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/tag/{}", self.base_url, id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .delete(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Lists all query bookmarks.
+    pub fn get_bookmarks(&self, token: &Option<String>) -> Result<Vec<Bookmark>, Error> {
+        let url = &format!("{}/api/v1/sec/querybookmark", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        let resp = self
+            .client
+            .get(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .context(HttpSnafu { url })?;
+
+        resp.error_for_status()
+            .context(HttpSnafu { url })?
+            .json::<Vec<Bookmark>>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Finds a bookmark by its id, its name or a prefix of its id.
+    pub fn find_bookmark(
+        &self,
+        token: &Option<String>,
+        id_or_name: &str,
+    ) -> Result<Bookmark, Error> {
+        let bookmarks = self.get_bookmarks(token)?;
+        find_unique("bookmark", id_or_name, bookmarks, |b| {
+            (b.id.as_str(), b.name.as_str())
+        })
+    }
+
+    /// Creates a new query bookmark. The `id` and `created`
+    /// properties are ignored.
+    pub fn create_bookmark(
+        &self,
+        token: &Option<String>,
+        bookmark: &Bookmark,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/querybookmark", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(bookmark)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Replaces the query bookmark with the same id with the given
+    /// data.
+    pub fn update_bookmark(
+        &self,
+        token: &Option<String>,
+        bookmark: &Bookmark,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/querybookmark", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .put(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(bookmark)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Deletes the query bookmark with the given id.
+    pub fn delete_bookmark(&self, token: &Option<String>, id: &str) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/querybookmark/{}", self.base_url, id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .delete(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Returns the state of the job queue of the collective. It
+    /// contains the queued, running and recently completed jobs.
+    pub fn get_queue_state(&self, token: &Option<String>) -> Result<JobQueueState, Error> {
+        let url = &format!("{}/api/v1/sec/queue/state", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .get(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<JobQueueState>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Finds a queued or running job by its id, its name or a prefix
+    /// of its id.
+    pub fn find_job(&self, token: &Option<String>, id_or_name: &str) -> Result<JobDetail, Error> {
+        let state = self.get_queue_state(token)?;
+        let jobs: Vec<JobDetail> = state.queued.into_iter().chain(state.progress).collect();
+        find_unique("job", id_or_name, jobs, |j| {
+            (j.id.as_str(), j.name.as_str())
+        })
+    }
+
+    /// Cancels the job with the given id. A running job is cancelled
+    /// by the server as soon as possible.
+    pub fn cancel_job(&self, token: &Option<String>, id: &str) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/queue/{}/cancel", self.base_url, id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .header(reqwest::header::CONTENT_LENGTH, 0)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Lists all shares of the collective. The `query` argument may
+    /// be used to filter shares by name.
+    pub fn list_shares(&self, token: &Option<String>, query: &str) -> Result<ShareList, Error> {
+        let url = &format!("{}/api/v1/sec/share", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .get(url)
+            .header(DOCSPELL_AUTH, token)
+            .query(&[("q", query)])
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<ShareList>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Finds a share by its id, its name or a prefix of its id.
+    pub fn find_share(
+        &self,
+        token: &Option<String>,
+        id_or_name: &str,
+    ) -> Result<ShareDetail, Error> {
+        let shares = self.list_shares(token, "")?;
+        find_unique("share", id_or_name, shares.items, |s| {
+            (s.id.as_str(), s.name.as_deref().unwrap_or(""))
+        })
+    }
+
+    /// Creates a new share. The result contains the id of the new
+    /// share, which is also its public identifier.
+    pub fn create_share(
+        &self,
+        token: &Option<String>,
+        data: &ShareData,
+    ) -> Result<IdResult, Error> {
+        let url = &format!("{}/api/v1/sec/share", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(data)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<IdResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Replaces the data of the share with the given id. The password
+    /// is only changed if one is given or `remove_password` is set.
+    pub fn update_share(
+        &self,
+        token: &Option<String>,
+        id: &str,
+        data: &ShareData,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/share/{}", self.base_url, id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .put(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(data)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Deletes the share with the given id.
+    pub fn delete_share(&self, token: &Option<String>, id: &str) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/share/{}", self.base_url, id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .delete(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Lists all notification channels of the current user.
+    pub fn list_channels(&self, token: &Option<String>) -> Result<Vec<NotificationChannel>, Error> {
+        let url = &format!("{}/api/v1/sec/notification/channel", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .get(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<Vec<NotificationChannel>>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Finds a notification channel by its id, its name or a prefix
+    /// of its id.
+    pub fn find_channel(
+        &self,
+        token: &Option<String>,
+        id_or_name: &str,
+    ) -> Result<NotificationChannel, Error> {
+        let channels = self.list_channels(token)?;
+        find_unique("channel", id_or_name, channels, |c| {
+            (c.id(), c.name().map(|n| n.as_str()).unwrap_or(""))
+        })
+    }
+
+    /// Creates a new notification channel. The `id` is ignored.
+    pub fn create_channel(
+        &self,
+        token: &Option<String>,
+        channel: &NotificationChannel,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/notification/channel", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(channel)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Deletes the notification channel with the given id.
+    pub fn delete_channel(&self, token: &Option<String>, id: &str) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/notification/channel/{}", self.base_url, id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .delete(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Lists all notification hooks of the current user.
+    pub fn list_hooks(&self, token: &Option<String>) -> Result<Vec<NotificationHook>, Error> {
+        let url = &format!("{}/api/v1/sec/notification/hook", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .get(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<Vec<NotificationHook>>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Finds a notification hook by its id or a prefix of it.
+    pub fn find_hook(&self, token: &Option<String>, id: &str) -> Result<NotificationHook, Error> {
+        let hooks = self.list_hooks(token)?;
+        find_unique("hook", id, hooks, |h| (h.id.as_str(), ""))
+    }
+
+    /// Creates a new notification hook. The `id` is ignored.
+    pub fn create_hook(
+        &self,
+        token: &Option<String>,
+        hook: &NotificationHook,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/notification/hook", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(hook)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Deletes the notification hook with the given id.
+    pub fn delete_hook(&self, token: &Option<String>, id: &str) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/notification/hook/{}", self.base_url, id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .delete(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Sends a sample event through the channels of the given hook.
+    pub fn test_hook(
+        &self,
+        token: &Option<String>,
+        hook: &NotificationHook,
+    ) -> Result<NotificationChannelTestResult, Error> {
+        let url = &format!(
+            "{}/api/v1/sec/notification/hook/sendTestEvent",
+            self.base_url
+        );
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(hook)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<NotificationChannelTestResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Lists all periodic query tasks of the current user.
+    pub fn list_periodic_queries(
+        &self,
+        token: &Option<String>,
+    ) -> Result<Vec<PeriodicQuerySettings>, Error> {
+        let url = &format!("{}/api/v1/sec/usertask/periodicquery", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .get(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<Vec<PeriodicQuerySettings>>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Finds a periodic query task by its id, its summary or a prefix
+    /// of its id.
+    pub fn find_periodic_query(
+        &self,
+        token: &Option<String>,
+        id_or_summary: &str,
+    ) -> Result<PeriodicQuerySettings, Error> {
+        let tasks = self.list_periodic_queries(token)?;
+        find_unique("periodic query", id_or_summary, tasks, |t| {
+            (t.id.as_str(), t.summary.as_deref().unwrap_or(""))
+        })
+    }
+
+    /// Creates a new periodic query task. The `id` is ignored.
+    pub fn create_periodic_query(
+        &self,
+        token: &Option<String>,
+        settings: &PeriodicQuerySettings,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/usertask/periodicquery", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(settings)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Replaces the periodic query task with the same id with the
+    /// given data.
+    pub fn update_periodic_query(
+        &self,
+        token: &Option<String>,
+        settings: &PeriodicQuerySettings,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/usertask/periodicquery", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .put(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(settings)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Deletes the periodic query task with the given id.
+    pub fn delete_periodic_query(
+        &self,
+        token: &Option<String>,
+        id: &str,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/usertask/periodicquery/{}", self.base_url, id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .delete(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Runs the given periodic query task once, independent of its
+    /// schedule.
+    pub fn start_periodic_query(
+        &self,
+        token: &Option<String>,
+        settings: &PeriodicQuerySettings,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!(
+            "{}/api/v1/sec/usertask/periodicquery/startonce",
+            self.base_url
+        );
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(settings)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Lists the SMTP connections of the current user. The
+    /// `query` argument may be used to filter them by name.
+    pub fn list_smtp_settings(
+        &self,
+        token: &Option<String>,
+        query: &str,
+    ) -> Result<EmailSettingsList, Error> {
+        let url = &format!("{}/api/v1/sec/email/settings/smtp", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .get(url)
+            .header(DOCSPELL_AUTH, token)
+            .query(&[("q", query)])
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<EmailSettingsList>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Creates a new SMTP connection.
+    pub fn create_smtp_settings(
+        &self,
+        token: &Option<String>,
+        settings: &EmailSettings,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/email/settings/smtp", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(settings)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Deletes the SMTP connection with the given name.
+    pub fn delete_smtp_settings(
+        &self,
+        token: &Option<String>,
+        name: &str,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!(
+            "{}/api/v1/sec/email/settings/smtp/{}",
+            self.base_url,
+            util::encode_path_segment(name)
+        );
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .delete(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Lists the IMAP connections of the current user. The
+    /// `query` argument may be used to filter them by name.
+    pub fn list_imap_settings(
+        &self,
+        token: &Option<String>,
+        query: &str,
+    ) -> Result<ImapSettingsList, Error> {
+        let url = &format!("{}/api/v1/sec/email/settings/imap", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .get(url)
+            .header(DOCSPELL_AUTH, token)
+            .query(&[("q", query)])
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<ImapSettingsList>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Creates a new IMAP connection.
+    pub fn create_imap_settings(
+        &self,
+        token: &Option<String>,
+        settings: &ImapSettings,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/email/settings/imap", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(settings)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Deletes the IMAP connection with the given name.
+    pub fn delete_imap_settings(
+        &self,
+        token: &Option<String>,
+        name: &str,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!(
+            "{}/api/v1/sec/email/settings/imap/{}",
+            self.base_url,
+            util::encode_path_segment(name)
+        );
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .delete(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Lists all scan-mailbox tasks of the current user.
+    pub fn list_scan_mailboxes(
+        &self,
+        token: &Option<String>,
+    ) -> Result<Vec<ScanMailboxSettings>, Error> {
+        let url = &format!("{}/api/v1/sec/usertask/scanmailbox", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .get(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<Vec<ScanMailboxSettings>>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Finds a scan-mailbox task by its id, its summary or a prefix
+    /// of its id.
+    pub fn find_scan_mailbox(
+        &self,
+        token: &Option<String>,
+        id_or_summary: &str,
+    ) -> Result<ScanMailboxSettings, Error> {
+        let tasks = self.list_scan_mailboxes(token)?;
+        find_unique("scan-mailbox task", id_or_summary, tasks, |t| {
+            (t.id.as_str(), t.summary.as_deref().unwrap_or(""))
+        })
+    }
+
+    /// Creates a new scan-mailbox task. The `id` is ignored.
+    pub fn create_scan_mailbox(
+        &self,
+        token: &Option<String>,
+        settings: &ScanMailboxSettings,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/usertask/scanmailbox", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(settings)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Replaces the scan-mailbox task with the same id with the given
+    /// data.
+    pub fn update_scan_mailbox(
+        &self,
+        token: &Option<String>,
+        settings: &ScanMailboxSettings,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/usertask/scanmailbox", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .put(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(settings)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Deletes the scan-mailbox task with the given id.
+    pub fn delete_scan_mailbox(
+        &self,
+        token: &Option<String>,
+        id: &str,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/usertask/scanmailbox/{}", self.base_url, id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .delete(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Runs the given scan-mailbox task once, independent of its
+    /// schedule.
+    pub fn start_scan_mailbox(
+        &self,
+        token: &Option<String>,
+        settings: &ScanMailboxSettings,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!(
+            "{}/api/v1/sec/usertask/scanmailbox/startonce",
+            self.base_url
+        );
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(settings)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Lists all organizations. The `query` argument may be a query
+    /// for a name, which can contain the `*` wildcard at beginning or
+    /// end.
+    pub fn list_organizations(
+        &self,
+        token: &Option<String>,
+        query: &str,
+    ) -> Result<OrganizationList, Error> {
+        let url = &format!("{}/api/v1/sec/organization", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .get(url)
+            .header(DOCSPELL_AUTH, token)
+            .query(&[("full", "true"), ("q", query)])
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<OrganizationList>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Finds an organization by its id, its name or a prefix of its
+    /// id.
+    pub fn find_organization(
+        &self,
+        token: &Option<String>,
+        id_or_name: &str,
+    ) -> Result<Organization, Error> {
+        let orgs = self.list_organizations(token, "")?;
+        find_unique("organization", id_or_name, orgs.items, |o| {
+            (o.id.as_str(), o.name.as_str())
+        })
+    }
+
+    /// Creates a new organization. The `id` and `created` properties
+    /// are ignored.
+    pub fn create_organization(
+        &self,
+        token: &Option<String>,
+        org: &Organization,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/organization", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(org)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Replaces the organization with the same id with the given data.
+    pub fn update_organization(
+        &self,
+        token: &Option<String>,
+        org: &Organization,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/organization", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .put(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(org)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Deletes the organization with the given id.
+    pub fn delete_organization(
+        &self,
+        token: &Option<String>,
+        id: &str,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/organization/{}", self.base_url, id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .delete(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Lists all persons. The `query` argument may be a query for a
+    /// name, which can contain the `*` wildcard at beginning or end.
+    pub fn list_persons(&self, token: &Option<String>, query: &str) -> Result<PersonList, Error> {
+        let url = &format!("{}/api/v1/sec/person", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .get(url)
+            .header(DOCSPELL_AUTH, token)
+            .query(&[("full", "true"), ("q", query)])
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<PersonList>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Finds a person by its id, its name or a prefix of its id.
+    pub fn find_person(&self, token: &Option<String>, id_or_name: &str) -> Result<Person, Error> {
+        let persons = self.list_persons(token, "")?;
+        find_unique("person", id_or_name, persons.items, |p| {
+            (p.id.as_str(), p.name.as_str())
+        })
+    }
+
+    /// Creates a new person. The `id` and `created` properties are
+    /// ignored.
+    pub fn create_person(
+        &self,
+        token: &Option<String>,
+        person: &Person,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/person", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(person)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Replaces the person with the same id with the given data.
+    pub fn update_person(
+        &self,
+        token: &Option<String>,
+        person: &Person,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/person", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .put(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(person)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Deletes the person with the given id.
+    pub fn delete_person(&self, token: &Option<String>, id: &str) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/person/{}", self.base_url, id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .delete(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Lists all equipments. The `query` argument may be a query for
+    /// a name, which can contain the `*` wildcard at beginning or end.
+    pub fn list_equipments(
+        &self,
+        token: &Option<String>,
+        query: &str,
+    ) -> Result<EquipmentList, Error> {
+        let url = &format!("{}/api/v1/sec/equipment", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .get(url)
+            .header(DOCSPELL_AUTH, token)
+            .query(&[("q", query)])
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<EquipmentList>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Finds an equipment by its id, its name or a prefix of its id.
+    pub fn find_equipment(
+        &self,
+        token: &Option<String>,
+        id_or_name: &str,
+    ) -> Result<Equipment, Error> {
+        let equips = self.list_equipments(token, "")?;
+        find_unique("equipment", id_or_name, equips.items, |e| {
+            (e.id.as_str(), e.name.as_str())
+        })
+    }
+
+    /// Creates a new equipment. The `id` and `created` properties are
+    /// ignored.
+    pub fn create_equipment(
+        &self,
+        token: &Option<String>,
+        equip: &Equipment,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/equipment", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(equip)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Replaces the equipment with the same id with the given data.
+    pub fn update_equipment(
+        &self,
+        token: &Option<String>,
+        equip: &Equipment,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/equipment", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .put(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(equip)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Deletes the equipment with the given id.
+    pub fn delete_equipment(&self, token: &Option<String>, id: &str) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/equipment/{}", self.base_url, id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .delete(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Lists all folders. The `query` argument may be a query for a
+    /// name, which can contain the `*` wildcard at beginning or end.
+    /// If `owning` is true, only folders owned by the current user are
+    /// returned.
+    pub fn list_folders(
+        &self,
+        token: &Option<String>,
+        query: &str,
+        owning: bool,
+    ) -> Result<FolderList, Error> {
+        let url = &format!("{}/api/v1/sec/folder", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .get(url)
+            .header(DOCSPELL_AUTH, token)
+            .query(&[("q", query), ("owning", owning.to_string().as_str())])
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<FolderList>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Finds a folder by its id, its name or a prefix of its id.
+    pub fn find_folder(
+        &self,
+        token: &Option<String>,
+        id_or_name: &str,
+    ) -> Result<FolderItem, Error> {
+        let folders = self.list_folders(token, "", false)?;
+        find_unique("folder", id_or_name, folders.items, |f| {
+            (f.id.as_str(), f.name.as_str())
+        })
+    }
+
+    /// Gets the details of a folder, including its members.
+    pub fn get_folder(&self, token: &Option<String>, id: &str) -> Result<FolderDetail, Error> {
+        let url = &format!("{}/api/v1/sec/folder/{}", self.base_url, id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .get(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<FolderDetail>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Creates a new folder owned by the current user.
+    pub fn create_folder(
+        &self,
+        token: &Option<String>,
+        folder: &NewFolder,
+    ) -> Result<IdResult, Error> {
+        let url = &format!("{}/api/v1/sec/folder", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(folder)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<IdResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Changes the name of the folder with the given id.
+    pub fn rename_folder(
+        &self,
+        token: &Option<String>,
+        id: &str,
+        folder: &NewFolder,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/folder/{}", self.base_url, id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .put(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(folder)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Deletes the folder with the given id.
+    pub fn delete_folder(&self, token: &Option<String>, id: &str) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/folder/{}", self.base_url, id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .delete(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Adds the user with the given id as a member to the folder.
+    pub fn add_folder_member(
+        &self,
+        token: &Option<String>,
+        folder_id: &str,
+        user_id: &str,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!(
+            "{}/api/v1/sec/folder/{}/member/{}",
+            self.base_url, folder_id, user_id
+        );
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .put(url)
+            .header(DOCSPELL_AUTH, token)
+            .header(reqwest::header::CONTENT_LENGTH, 0)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Removes the user with the given id from the folder members.
+    pub fn remove_folder_member(
+        &self,
+        token: &Option<String>,
+        folder_id: &str,
+        user_id: &str,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!(
+            "{}/api/v1/sec/folder/{}/member/{}",
+            self.base_url, folder_id, user_id
+        );
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .delete(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Lists all users of the current collective.
+    pub fn list_users(&self, token: &Option<String>) -> Result<UserList, Error> {
+        let url = &format!("{}/api/v1/sec/user", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .get(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<UserList>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Finds a user of the current collective by its id, its login or
+    /// a prefix of its id.
+    pub fn find_user(&self, token: &Option<String>, id_or_login: &str) -> Result<User, Error> {
+        let users = self.list_users(token)?;
+        find_unique("user", id_or_login, users.items, |u| {
+            (u.id.as_str(), u.login.as_str())
+        })
+    }
+
+    /// Lists all custom field definitions. The `query` argument may be
+    /// a query for a name, which can contain the `*` wildcard at
+    /// beginning or end.
+    pub fn list_custom_fields(
+        &self,
+        token: &Option<String>,
+        query: &str,
+    ) -> Result<CustomFieldDefList, Error> {
+        let url = &format!("{}/api/v1/sec/customfield", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .get(url)
+            .header(DOCSPELL_AUTH, token)
+            .query(&[("q", query)])
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<CustomFieldDefList>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Finds a custom field definition by its id, its name or a prefix
+    /// of its id.
+    pub fn find_custom_field(
+        &self,
+        token: &Option<String>,
+        id_or_name: &str,
+    ) -> Result<CustomFieldDef, Error> {
+        let fields = self.list_custom_fields(token, "")?;
+        find_unique("custom field", id_or_name, fields.items, |f| {
+            (f.id.as_str(), f.name.as_str())
+        })
+    }
+
+    /// Creates a new custom field definition.
+    pub fn create_custom_field(
+        &self,
+        token: &Option<String>,
+        field: &NewCustomField,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/customfield", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(field)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Changes the custom field definition with the given id.
+    pub fn update_custom_field(
+        &self,
+        token: &Option<String>,
+        id: &str,
+        field: &NewCustomField,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/customfield/{}", self.base_url, id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .put(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(field)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Deletes the custom field definition with the given id. This
+    /// removes the field from all items.
+    pub fn delete_custom_field(
+        &self,
+        token: &Option<String>,
+        id: &str,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/customfield/{}", self.base_url, id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .delete(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Get all item details. The item is identified by its id. The id
+    /// may be a prefix only, in this case another request is used to
+    /// find the complete id.
+    ///
+    /// If `token` is specified, it is used to authenticate. Otherwise
+    /// a stored session is used.
+    pub fn get_item<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+    ) -> Result<Option<ItemDetail>, Error> {
+        let item_id = self.complete_item_id(token, id.as_ref(), SearchMode::All)?;
+        if let Some(iid) = item_id {
+            let url = &format!("{}/api/v1/sec/item/{}", self.base_url, iid);
+            let token = session::session_token(token, self).context(SessionSnafu)?;
+            let resp = self
+                .client
+                .get(url)
+                .header(DOCSPELL_AUTH, token)
+                .send()
+                .context(HttpSnafu { url })?;
+
+            if resp.status() == StatusCode::NOT_FOUND {
+                Ok(None)
+            } else {
+                resp.error_for_status()
+                    .context(HttpSnafu { url })?
+                    .json::<ItemDetail>()
+                    .context(SerializeRespSnafu)
+                    .map(Some)
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Adds the give tags to the item with the given id. The id may
+    /// be given as a prefix, then another request is used to find the
+    /// complete id.
+    ///
+    /// Tags can be given via their ids or names. They must exist or
+    /// are ignored otherwise.
+    pub fn link_tags<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+        tags: &StringList,
+    ) -> Result<BasicResult, Error> {
+        let item_id = self.require_item_id(token, id, SearchMode::All)?;
+        let url = &format!("{}/api/v1/sec/item/{}/taglink", self.base_url, item_id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .put(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(tags)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Replaces the given tags on the item with the given id. The id
+    /// may be given abbreviated as a prefix, then another request is
+    /// used to find the complete id.
+    ///
+    /// Tags can be given via their names or ids.
+    pub fn set_tags<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+        tags: &StringList,
+    ) -> Result<BasicResult, Error> {
+        let item_id = self.require_item_id(token, id, SearchMode::All)?;
+        let url = &format!("{}/api/v1/sec/item/{}/tags", self.base_url, item_id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .put(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(tags)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Removes the given tags on the item with the given id. The id
+    /// may be given abbreviated as a prefix, then another request is
+    /// used to find the complete id.
+    ///
+    /// Tags can be given via their names or ids.
+    pub fn remove_tags<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+        tags: &StringList,
+    ) -> Result<BasicResult, Error> {
+        let item_id = self.require_item_id(token, id, SearchMode::All)?;
+        let url = &format!("{}/api/v1/sec/item/{}/tagsremove", self.base_url, item_id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(tags)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Sets the field to the specfified value for the given item.
+    pub fn set_field<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+        fvalue: &CustomFieldValue,
+    ) -> Result<BasicResult, Error> {
+        let item_id = self.require_item_id(token, id, SearchMode::All)?;
+        let url = &format!("{}/api/v1/sec/item/{}/customfield", self.base_url, item_id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .put(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(fvalue)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Removes the field from the given item.
+    pub fn remove_field<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+        field: &str,
+    ) -> Result<BasicResult, Error> {
+        let item_id = self.require_item_id(token, id, SearchMode::All)?;
+        let url = &format!(
+            "{}/api/v1/sec/item/{}/customfield/{}",
+            self.base_url, item_id, field
+        );
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .delete(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Sets the name of the given item.
+    pub fn set_item_name<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+        name: &str,
+    ) -> Result<BasicResult, Error> {
+        self.put_item_property(
+            token,
+            id,
+            "name",
+            &OptionalText {
+                text: Some(name.to_string()),
+            },
+        )
+    }
+
+    /// Sets or removes the notes of the given item.
+    pub fn set_item_notes<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+        notes: Option<&str>,
+    ) -> Result<BasicResult, Error> {
+        self.put_item_property(
+            token,
+            id,
+            "notes",
+            &OptionalText {
+                text: notes.map(String::from),
+            },
+        )
+    }
+
+    /// Sets or removes the date of the given item. The date is given
+    /// as a unix timestamp in milliseconds.
+    pub fn set_item_date<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+        date: Option<i64>,
+    ) -> Result<BasicResult, Error> {
+        self.put_item_property(token, id, "date", &OptionalDate { date })
+    }
+
+    /// Sets or removes the due date of the given item. The date is
+    /// given as a unix timestamp in milliseconds.
+    pub fn set_item_due_date<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+        date: Option<i64>,
+    ) -> Result<BasicResult, Error> {
+        self.put_item_property(token, id, "duedate", &OptionalDate { date })
+    }
+
+    /// Sets the direction of the given item, which is either
+    /// `incoming` or `outgoing`.
+    pub fn set_item_direction<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+        direction: &str,
+    ) -> Result<BasicResult, Error> {
+        self.put_item_property(
+            token,
+            id,
+            "direction",
+            &DirectionValue {
+                direction: direction.to_string(),
+            },
+        )
+    }
+
+    /// Sets or removes the correspondent organization of the given item. It
+    /// must be given by its complete id.
+    pub fn set_item_corr_org<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+        ref_id: Option<&str>,
+    ) -> Result<BasicResult, Error> {
+        self.put_item_property(
+            token,
+            id,
+            "corrOrg",
+            &OptionalId {
+                id: ref_id.map(String::from),
+            },
+        )
+    }
+
+    /// Sets or removes the correspondent person of the given item. It
+    /// must be given by its complete id.
+    pub fn set_item_corr_person<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+        ref_id: Option<&str>,
+    ) -> Result<BasicResult, Error> {
+        self.put_item_property(
+            token,
+            id,
+            "corrPerson",
+            &OptionalId {
+                id: ref_id.map(String::from),
+            },
+        )
+    }
+
+    /// Sets or removes the concerning person of the given item. It
+    /// must be given by its complete id.
+    pub fn set_item_conc_person<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+        ref_id: Option<&str>,
+    ) -> Result<BasicResult, Error> {
+        self.put_item_property(
+            token,
+            id,
+            "concPerson",
+            &OptionalId {
+                id: ref_id.map(String::from),
+            },
+        )
+    }
+
+    /// Sets or removes the concerning equipment of the given item. It
+    /// must be given by its complete id.
+    pub fn set_item_conc_equipment<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+        ref_id: Option<&str>,
+    ) -> Result<BasicResult, Error> {
+        self.put_item_property(
+            token,
+            id,
+            "concEquipment",
+            &OptionalId {
+                id: ref_id.map(String::from),
+            },
+        )
+    }
+
+    /// Sets or removes the folder of the given item. It
+    /// must be given by its complete id.
+    pub fn set_item_folder<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+        ref_id: Option<&str>,
+    ) -> Result<BasicResult, Error> {
+        self.put_item_property(
+            token,
+            id,
+            "folder",
+            &OptionalId {
+                id: ref_id.map(String::from),
+            },
+        )
+    }
+
+    /// Confirms the given item, which marks it as reviewed.
+    pub fn confirm_item<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+    ) -> Result<BasicResult, Error> {
+        let item_id = self.require_item_id(token, id, SearchMode::All)?;
+        let url = &format!("{}/api/v1/sec/item/{}/confirm", self.base_url, item_id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .header(reqwest::header::CONTENT_LENGTH, 0)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Sends the given mail about an item via the SMTP connection
+    /// with the given name. The item id must be complete.
+    pub fn send_item_mail(
+        &self,
+        token: &Option<String>,
+        connection: &str,
+        item_id: &str,
+        mail: &SimpleMail,
+    ) -> Result<BasicResult, Error> {
+        let url = &format!(
+            "{}/api/v1/sec/email/send/{}/{}",
+            self.base_url,
+            util::encode_path_segment(connection),
+            item_id
+        );
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(mail)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Returns the mails that have been sent for the given item. The
+    /// id must be complete.
+    pub fn get_sent_mails(
+        &self,
+        token: &Option<String>,
+        item_id: &str,
+    ) -> Result<SentMails, Error> {
+        let url = &format!("{}/api/v1/sec/email/sent/item/{}", self.base_url, item_id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .get(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<SentMails>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Reverts the confirmation of the given item, so it appears in
+    /// the inbox again.
+    pub fn unconfirm_item<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+    ) -> Result<BasicResult, Error> {
+        let item_id = self.require_item_id(token, id, SearchMode::All)?;
+        let url = &format!("{}/api/v1/sec/item/{}/unconfirm", self.base_url, item_id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .header(reqwest::header::CONTENT_LENGTH, 0)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Moves the given item into the trash. It can be restored until
+    /// the trash is emptied.
+    pub fn delete_item<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+    ) -> Result<BasicResult, Error> {
+        let item_id = self.require_item_id(token, id, SearchMode::All)?;
+        let url = &format!("{}/api/v1/sec/item/{}", self.base_url, item_id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .delete(url)
+            .header(DOCSPELL_AUTH, token)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Restores the given item from the trash.
+    pub fn restore_item<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+    ) -> Result<BasicResult, Error> {
+        let item_id = self.require_item_id(token, id, SearchMode::All)?;
+        let url = &format!("{}/api/v1/sec/item/{}/restore", self.base_url, item_id);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .header(reqwest::header::CONTENT_LENGTH, 0)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Merges all given items into the first one. The attachments and
+    /// metadata of the other items are added to the first item and the
+    /// other items are deleted afterwards. Ids may be abbreviated.
+    pub fn merge_items<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        ids: &[S],
+    ) -> Result<BasicResult, Error> {
+        let ids = ids
+            .iter()
+            .map(|id| self.require_item_id(token, id, SearchMode::All))
+            .collect::<Result<Vec<String>, Error>>()?;
+        let url = &format!("{}/api/v1/sec/items/merge", self.base_url);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .post(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(&IdList { ids })
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Submits a job to reprocess the given item. If `attachment_ids`
+    /// is empty, all attachments are reprocessed, otherwise only the
+    /// given ones. The item id may be abbreviated.
+    pub fn reprocess_item<S: AsRef<str>>(
         &self,
         token: &Option<String>,
-        query: S,
-    ) -> Result<Summary, Error> {
-        let url = &format!("{}/api/v1/sec/item/searchStats", self.base_url);
+        id: S,
+        attachment_ids: &[String],
+    ) -> Result<BasicResult, Error> {
+        let item_id = self.require_item_id(token, id, SearchMode::All)?;
+        let url = &format!("{}/api/v1/sec/item/{}/reprocess", self.base_url, item_id);
         let token = session::session_token(token, self).context(SessionSnafu)?;
         self.client
-            .get(url)
+            .post(url)
             .header(DOCSPELL_AUTH, token)
-            .query(&[("q", &query.into())])
+            .json(&StringList {
+                items: attachment_ids.to_vec(),
+            })
             .send()
             .and_then(|r| r.error_for_status())
             .context(HttpSnafu { url })?
-            .json::<Summary>()
+            .json::<BasicResult>()
             .context(SerializeRespSnafu)
     }
 
-    /// Lists all sources for the current user.
-    ///
-    /// If `token` is specified, it is used to authenticate. Otherwise
-    /// a stored session is used.
-    pub fn list_sources(&self, token: &Option<String>) -> Result<SourceList, Error> {
-        let url = &format!("{}/api/v1/sec/source", self.base_url);
+    /// Submits jobs to reprocess all attachments of the given items.
+    /// The ids must be complete.
+    pub fn reprocess_items(
+        &self,
+        token: &Option<String>,
+        ids: &[String],
+    ) -> Result<BasicResult, Error> {
+        let url = &format!("{}/api/v1/sec/items/reprocess", self.base_url);
         let token = session::session_token(token, self).context(SessionSnafu)?;
         self.client
-            .get(url)
+            .post(url)
             .header(DOCSPELL_AUTH, token)
+            .json(&IdList { ids: ids.to_vec() })
             .send()
             .and_then(|r| r.error_for_status())
             .context(HttpSnafu { url })?
-            .json::<SourceList>()
+            .json::<BasicResult>()
             .context(SerializeRespSnafu)
     }
 
-    /// Lists all tags. The `query` argument may be a query for a
-    /// name, which can contain the `*` wildcard at beginning or end.
-    pub fn list_tags(&self, token: &Option<String>, query: &str) -> Result<TagList, Error> {
-        let url = &format!("{}/api/v1/sec/tag", self.base_url);
+    /// Returns the proposals for the given item, that have been found
+    /// when processing its attachments. The id may be abbreviated.
+    pub fn get_item_proposals<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+    ) -> Result<ItemProposals, Error> {
+        let item_id = self.require_item_id(token, id, SearchMode::All)?;
+        let url = &format!("{}/api/v1/sec/item/{}/proposals", self.base_url, item_id);
         let token = session::session_token(token, self).context(SessionSnafu)?;
         self.client
             .get(url)
             .header(DOCSPELL_AUTH, token)
-            .query(&[("q", query)])
             .send()
             .and_then(|r| r.error_for_status())
             .context(HttpSnafu { url })?
-            .json::<TagList>()
+            .json::<ItemProposals>()
             .context(SerializeRespSnafu)
     }
 
-    /// Lists all query bookmarks.
-    pub fn get_bookmarks(&self, token: &Option<String>) -> Result<Vec<Bookmark>, Error> {
-        let url = &format!("{}/api/v1/sec/querybookmark", self.base_url);
+    /// Submits a task that permanently deletes all items in the trash
+    /// that are older than `min_age` (in milliseconds).
+    pub fn empty_trash(&self, token: &Option<String>, min_age: i64) -> Result<BasicResult, Error> {
+        let url = &format!(
+            "{}/api/v1/sec/collective/emptytrash/startonce",
+            self.base_url
+        );
         let token = session::session_token(token, self).context(SessionSnafu)?;
-        let resp = self
-            .client
-            .get(url)
+        // the schedule is required, but not used when running once
+        let req = EmptyTrashSetting {
+            schedule: "*-*-* 01:00:00".into(),
+            min_age,
+        };
+        self.client
+            .post(url)
             .header(DOCSPELL_AUTH, token)
+            .json(&req)
             .send()
-            .context(HttpSnafu { url })?;
-
-        resp.error_for_status()
+            .and_then(|r| r.error_for_status())
             .context(HttpSnafu { url })?
-            .json::<Vec<Bookmark>>()
+            .json::<BasicResult>()
             .context(SerializeRespSnafu)
     }
 
-    /// Get all item details. The item is identified by its id. The id
-    /// may be a prefix only, in this case another request is used to
-    /// find the complete id.
-    ///
-    /// If `token` is specified, it is used to authenticate. Otherwise
-    /// a stored session is used.
-    pub fn get_item<S: AsRef<str>>(
+    /// Finds an attachment of the given item by its id, its name or a
+    /// prefix of its id. The item id may be abbreviated.
+    pub fn find_attachment<S: AsRef<str>>(
         &self,
         token: &Option<String>,
-        id: S,
-    ) -> Result<Option<ItemDetail>, Error> {
-        let item_id = self.complete_item_id(token, id.as_ref(), SearchMode::All)?;
-        if let Some(iid) = item_id {
-            let url = &format!("{}/api/v1/sec/item/{}", self.base_url, iid);
-            let token = session::session_token(token, self).context(SessionSnafu)?;
-            let resp = self
-                .client
-                .get(url)
-                .header(DOCSPELL_AUTH, token)
-                .send()
-                .context(HttpSnafu { url })?;
-
-            if resp.status() == StatusCode::NOT_FOUND {
-                Ok(None)
-            } else {
-                resp.error_for_status()
-                    .context(HttpSnafu { url })?
-                    .json::<ItemDetail>()
-                    .context(SerializeRespSnafu)
-                    .map(Some)
-            }
-        } else {
-            Ok(None)
-        }
+        item_id: S,
+        id_or_name: &str,
+    ) -> Result<Attachment, Error> {
+        let item = self.get_item(token, &item_id)?.ok_or(Error::ItemNotFound {
+            id: item_id.as_ref().to_string(),
+        })?;
+        find_unique("attachment", id_or_name, item.attachments, |a| {
+            (a.id.as_str(), a.name.as_deref().unwrap_or(""))
+        })
     }
 
-    /// Adds the give tags to the item with the given id. The id may
-    /// be given as a prefix, then another request is used to find the
-    /// complete id.
-    ///
-    /// Tags can be given via their ids or names. They must exist or
-    /// are ignored otherwise.
-    pub fn link_tags<S: AsRef<str>>(
+    /// Returns the metadata of the attachment with the given id. This
+    /// includes the extracted text and the proposals found in it.
+    pub fn get_attachment_meta(
         &self,
         token: &Option<String>,
-        id: S,
-        tags: &StringList,
-    ) -> Result<BasicResult, Error> {
-        let item_id = self.require_item_id(token, id, SearchMode::All)?;
-        let url = &format!("{}/api/v1/sec/item/{}/taglink", self.base_url, item_id);
+        id: &str,
+    ) -> Result<AttachmentMeta, Error> {
+        let url = &format!("{}/api/v1/sec/attachment/{}/meta", self.base_url, id);
         let token = session::session_token(token, self).context(SessionSnafu)?;
         self.client
-            .put(url)
+            .get(url)
             .header(DOCSPELL_AUTH, token)
-            .json(tags)
             .send()
             .and_then(|r| r.error_for_status())
             .context(HttpSnafu { url })?
-            .json::<BasicResult>()
+            .json::<AttachmentMeta>()
             .context(SerializeRespSnafu)
     }
 
-    /// Replaces the given tags on the item with the given id. The id
-    /// may be given abbreviated as a prefix, then another request is
-    /// used to find the complete id.
-    ///
-    /// Tags can be given via their names or ids.
-    pub fn set_tags<S: AsRef<str>>(
+    /// Sets or removes the name of the attachment with the given id.
+    pub fn rename_attachment(
         &self,
         token: &Option<String>,
-        id: S,
-        tags: &StringList,
+        id: &str,
+        name: Option<&str>,
     ) -> Result<BasicResult, Error> {
-        let item_id = self.require_item_id(token, id, SearchMode::All)?;
-        let url = &format!("{}/api/v1/sec/item/{}/tags", self.base_url, item_id);
+        let url = &format!("{}/api/v1/sec/attachment/{}/name", self.base_url, id);
         let token = session::session_token(token, self).context(SessionSnafu)?;
         self.client
-            .put(url)
+            .post(url)
             .header(DOCSPELL_AUTH, token)
-            .json(tags)
+            .json(&OptionalText {
+                text: name.map(String::from),
+            })
             .send()
             .and_then(|r| r.error_for_status())
             .context(HttpSnafu { url })?
@@ -453,24 +2385,17 @@ impl Client {
             .context(SerializeRespSnafu)
     }
 
-    /// Removes the given tags on the item with the given id. The id
-    /// may be given abbreviated as a prefix, then another request is
-    /// used to find the complete id.
-    ///
-    /// Tags can be given via their names or ids.
-    pub fn remove_tags<S: AsRef<str>>(
+    /// Deletes the attachment with the given id.
+    pub fn delete_attachment(
         &self,
         token: &Option<String>,
-        id: S,
-        tags: &StringList,
+        id: &str,
     ) -> Result<BasicResult, Error> {
-        let item_id = self.require_item_id(token, id, SearchMode::All)?;
-        let url = &format!("{}/api/v1/sec/item/{}/tagsremove", self.base_url, item_id);
+        let url = &format!("{}/api/v1/sec/attachment/{}", self.base_url, id);
         let token = session::session_token(token, self).context(SessionSnafu)?;
         self.client
-            .post(url)
+            .delete(url)
             .header(DOCSPELL_AUTH, token)
-            .json(tags)
             .send()
             .and_then(|r| r.error_for_status())
             .context(HttpSnafu { url })?
@@ -478,20 +2403,29 @@ impl Client {
             .context(SerializeRespSnafu)
     }
 
-    /// Sets the field to the specfified value for the given item.
-    pub fn set_field<S: AsRef<str>>(
+    /// Moves the attachment `source` of the given item before the
+    /// attachment `target`. The item id may be abbreviated, the
+    /// attachment ids must be complete.
+    pub fn move_attachment_before<S: AsRef<str>>(
         &self,
         token: &Option<String>,
-        id: S,
-        fvalue: &CustomFieldValue,
+        item_id: S,
+        source: &str,
+        target: &str,
     ) -> Result<BasicResult, Error> {
-        let item_id = self.require_item_id(token, id, SearchMode::All)?;
-        let url = &format!("{}/api/v1/sec/item/{}/customfield", self.base_url, item_id);
+        let item_id = self.require_item_id(token, item_id, SearchMode::All)?;
+        let url = &format!(
+            "{}/api/v1/sec/item/{}/attachment/movebefore",
+            self.base_url, item_id
+        );
         let token = session::session_token(token, self).context(SessionSnafu)?;
         self.client
-            .put(url)
+            .post(url)
             .header(DOCSPELL_AUTH, token)
-            .json(fvalue)
+            .json(&MoveAttachment {
+                source: source.to_string(),
+                target: target.to_string(),
+            })
             .send()
             .and_then(|r| r.error_for_status())
             .context(HttpSnafu { url })?
@@ -499,22 +2433,29 @@ impl Client {
             .context(SerializeRespSnafu)
     }
 
-    /// Removes the field from the given item.
-    pub fn remove_field<S: AsRef<str>>(
+    /// Uploads a downloaded file to an existing item, where it is added
+    /// as a new attachment after processing. The item id may be
+    /// abbreviated. The file is streamed from the download into the
+    /// upload request.
+    pub fn upload_to_item<S: AsRef<str>>(
         &self,
         token: &Option<String>,
-        id: S,
-        field: &str,
+        item_id: S,
+        file_name: &str,
+        file: Download,
     ) -> Result<BasicResult, Error> {
-        let item_id = self.require_item_id(token, id, SearchMode::All)?;
-        let url = &format!(
-            "{}/api/v1/sec/item/{}/customfield/{}",
-            self.base_url, item_id, field
-        );
+        let item_id = self.require_item_id(token, item_id, SearchMode::All)?;
+        let url = &format!("{}/api/v1/sec/upload/item/{}", self.base_url, item_id);
         let token = session::session_token(token, self).context(SessionSnafu)?;
+        let part = match file.resp.content_length() {
+            Some(len) => Part::reader_with_length(file.resp, len),
+            None => Part::reader(file.resp),
+        };
+        let form = Form::new().part("file", part.file_name(file_name.to_string()));
         self.client
-            .delete(url)
+            .post(url)
             .header(DOCSPELL_AUTH, token)
+            .multipart(form)
             .send()
             .and_then(|r| r.error_for_status())
             .context(HttpSnafu { url })?
@@ -526,14 +2467,14 @@ impl Client {
     /// of the results. The attachments can be downloaded by calling
     /// the corresponding functions on the iterators elements.
     ///
-    /// If `token` is specified, it is used to authenticate. Otherwise
-    /// a stored session is used.
+    /// With a share token, only attachments of the public share are
+    /// returned.
     pub fn download_search(
         &self,
-        token: &Option<String>,
+        auth: &DownloadAuth,
         req: &SearchReq,
     ) -> Result<Downloads, Error> {
-        let results = self.search(token, req)?;
+        let results = self.search_with(auth, req)?;
         Ok(Downloads::from_results(&results))
     }
 
@@ -553,6 +2494,22 @@ impl Client {
         Ok(Downloads::from_item_detail(&item))
     }
 
+    /// Gets the preview image of the given item, which is the preview
+    /// of its first attachment. The id may be abbreviated.
+    pub fn download_item_preview<S: AsRef<str>>(
+        &self,
+        token: &Option<String>,
+        id: S,
+    ) -> Result<Option<Download>, Error> {
+        let item_id = self.require_item_id(token, id, SearchMode::All)?;
+        let url = format!("{}/api/v1/sec/item/{}/preview", self.base_url, item_id);
+        DownloadRef::new(item_id.clone(), item_id).get_file(
+            self,
+            &DownloadAuth::from_session(token),
+            &url,
+        )
+    }
+
     /// Checks if the integration endpoint is enabled for the given collective.
     pub fn int_endpoint_avail(&self, data: IntegrationData) -> Result<bool, Error> {
         let url = format!(
@@ -840,7 +2797,32 @@ impl Client {
 
     // --- Helpers
 
-    fn require_item_id<S: AsRef<str>>(
+    /// Changes a single property of an item via a `PUT` request to
+    /// `/sec/item/{id}/{property}`. The item id may be abbreviated.
+    fn put_item_property<S: AsRef<str>, B: Serialize>(
+        &self,
+        token: &Option<String>,
+        id: S,
+        property: &str,
+        body: &B,
+    ) -> Result<BasicResult, Error> {
+        let item_id = self.require_item_id(token, id, SearchMode::All)?;
+        let url = &format!("{}/api/v1/sec/item/{}/{}", self.base_url, item_id, property);
+        let token = session::session_token(token, self).context(SessionSnafu)?;
+        self.client
+            .put(url)
+            .header(DOCSPELL_AUTH, token)
+            .json(body)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .context(HttpSnafu { url })?
+            .json::<BasicResult>()
+            .context(SerializeRespSnafu)
+    }
+
+    /// Returns the complete id of the item given by a possibly
+    /// abbreviated id. Fails if no unique item can be found.
+    pub fn require_item_id<S: AsRef<str>>(
         &self,
         token: &Option<String>,
         partial_id: S,
@@ -893,6 +2875,108 @@ impl Client {
     }
 }
 
+/// Finds the single element that is identified by `key`. The key is
+/// compared to the complete id first, then to the name (ignoring
+/// case) and at last it is used as a prefix of the id. An empty key
+/// never matches.
+fn find_unique<A, F>(kind: &str, key: &str, mut elements: Vec<A>, id_name: F) -> Result<A, Error>
+where
+    F: Fn(&A) -> (&str, &str),
+{
+    if key.is_empty() {
+        return Err(Error::NotFound {
+            kind: kind.into(),
+            key: key.into(),
+        });
+    }
+    let positions: Vec<usize> = match elements.iter().position(|e| id_name(e).0 == key) {
+        Some(pos) => vec![pos],
+        None => {
+            let by_name: Vec<usize> = elements
+                .iter()
+                .enumerate()
+                .filter(|(_, e)| id_name(*e).1.eq_ignore_ascii_case(key))
+                .map(|(idx, _)| idx)
+                .collect();
+            if by_name.is_empty() {
+                elements
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, e)| id_name(*e).0.starts_with(key))
+                    .map(|(idx, _)| idx)
+                    .collect()
+            } else {
+                by_name
+            }
+        }
+    };
+
+    match positions.as_slice() {
+        [pos] => Ok(elements.swap_remove(*pos)),
+        [] => Err(Error::NotFound {
+            kind: kind.into(),
+            key: key.into(),
+        }),
+        _ => Err(Error::NotUnique {
+            kind: kind.into(),
+            key: key.into(),
+        }),
+    }
+}
+
+/// Defines methods to authenticate when downloading files.
+///
+/// Either use the session or a token of a [public
+/// share](https://docspell.org/docs/webapp/share/), which is obtained
+/// via `Client::verify_share`.
+#[derive(Clone)]
+pub enum DownloadAuth {
+    Session { token: Option<String> },
+    Share { token: String },
+}
+
+impl DownloadAuth {
+    pub fn from_session(token: &Option<String>) -> DownloadAuth {
+        DownloadAuth::Session {
+            token: token.clone(),
+        }
+    }
+
+    pub fn from_share<S: Into<String>>(token: S) -> DownloadAuth {
+        DownloadAuth::Share {
+            token: token.into(),
+        }
+    }
+
+    /// The part of the url that distinguishes the secured from the
+    /// share routes.
+    fn route(&self) -> &'static str {
+        match self {
+            DownloadAuth::Session { .. } => "sec",
+            DownloadAuth::Share { .. } => "share",
+        }
+    }
+
+    /// Fails for share authentication, as the share routes only
+    /// provide the converted file and preview of an attachment.
+    fn require_session(&self, what: &str) -> Result<(), Error> {
+        match self {
+            DownloadAuth::Session { .. } => Ok(()),
+            DownloadAuth::Share { .. } => Err(Error::ShareUnsupported { what: what.into() }),
+        }
+    }
+
+    fn apply(&self, client: &Client, rb: RequestBuilder) -> Result<RequestBuilder, Error> {
+        match self {
+            DownloadAuth::Session { token } => {
+                let h = session::session_token(token, client).context(SessionSnafu)?;
+                Ok(rb.header(DOCSPELL_AUTH, h))
+            }
+            DownloadAuth::Share { token } => Ok(rb.header(DOCSPELL_SHARE_AUTH, token)),
+        }
+    }
+}
+
 /// Defines methods to authenticate when uploading files.
 ///
 /// Either use a [source
@@ -900,6 +2984,7 @@ impl Client {
 /// use the [integration
 /// endpoint](https://docspell.org/docs/api/upload/#integration-endpoint)
 /// or the session.
+#[derive(Clone)]
 pub enum FileAuth {
     Source { id: String },
     Integration(IntegrationData),
@@ -908,6 +2993,7 @@ pub enum FileAuth {
 
 /// When using the integration endpoint, a collective id is required
 /// and possibly some authentication information.
+#[derive(Clone)]
 pub struct IntegrationData {
     pub collective: String,
     pub auth: IntegrationAuth,
@@ -916,6 +3002,7 @@ pub struct IntegrationData {
 /// The integration endpoint allows several authentication methods:
 /// via http basic, some other specific header or without any extra
 /// data (using fixed ip addresses).
+#[derive(Clone)]
 pub enum IntegrationAuth {
     Header(String, String),
     Basic(String, String),
@@ -984,6 +3071,15 @@ impl Download {
             .and_then(util::filename_from_header)
     }
 
+    /// Get the content type from the responses `Content-Type` header.
+    pub fn get_content_type(&self) -> Option<String> {
+        self.resp
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|hv| hv.to_str().ok())
+            .map(String::from)
+    }
+
     /// Copies the bytes from the response into the give writer.
     pub fn copy_to<W: ?Sized>(&mut self, w: &mut W) -> Result<u64, Error>
     where
@@ -1016,57 +3112,86 @@ impl DownloadRef {
         }
     }
 
-    pub fn has_archive(&self, client: &Client, token: &Option<String>) -> Result<bool, Error> {
+    pub fn has_archive(&self, client: &Client, auth: &DownloadAuth) -> Result<bool, Error> {
+        auth.require_session("archive")?;
         let url = format!(
-            "{}/api/v1/sec/attachment/{}/archive",
-            client.base_url, self.id
+            "{}/api/v1/{}/attachment/{}/archive",
+            client.base_url,
+            auth.route(),
+            self.id
         );
-        self.head_file(client, token, &url)
+        self.head_file(client, auth, &url)
     }
 
-    pub fn get(&self, client: &Client, token: &Option<String>) -> Result<Option<Download>, Error> {
-        let url = format!("{}/api/v1/sec/attachment/{}", client.base_url, self.id);
-        self.get_file(client, token, &url)
+    pub fn get(&self, client: &Client, auth: &DownloadAuth) -> Result<Option<Download>, Error> {
+        let url = format!(
+            "{}/api/v1/{}/attachment/{}",
+            client.base_url,
+            auth.route(),
+            self.id
+        );
+        self.get_file(client, auth, &url)
     }
 
     pub fn get_original(
         &self,
         client: &Client,
-        token: &Option<String>,
+        auth: &DownloadAuth,
     ) -> Result<Option<Download>, Error> {
+        auth.require_session("original file")?;
         let url = format!(
-            "{}/api/v1/sec/attachment/{}/original",
-            client.base_url, self.id
+            "{}/api/v1/{}/attachment/{}/original",
+            client.base_url,
+            auth.route(),
+            self.id
         );
-        self.get_file(client, token, &url)
+        self.get_file(client, auth, &url)
     }
 
     pub fn get_archive(
         &self,
         client: &Client,
-        token: &Option<String>,
+        auth: &DownloadAuth,
+    ) -> Result<Option<Download>, Error> {
+        auth.require_session("archive")?;
+        let url = format!(
+            "{}/api/v1/{}/attachment/{}/archive",
+            client.base_url,
+            auth.route(),
+            self.id
+        );
+        self.get_file(client, auth, &url)
+    }
+
+    /// Gets the preview image of the attachment, which is a rendered
+    /// image of its first page.
+    pub fn get_preview(
+        &self,
+        client: &Client,
+        auth: &DownloadAuth,
     ) -> Result<Option<Download>, Error> {
         let url = format!(
-            "{}/api/v1/sec/attachment/{}/archive",
-            client.base_url, self.id
+            "{}/api/v1/{}/attachment/{}/preview",
+            client.base_url,
+            auth.route(),
+            self.id
         );
-        self.get_file(client, token, &url)
+        self.get_file(client, auth, &url)
     }
 
     fn get_file(
         &self,
         client: &Client,
-        token: &Option<String>,
+        auth: &DownloadAuth,
         url: &str,
     ) -> Result<Option<Download>, Error> {
-        let token = session::session_token(token, client).context(SessionSnafu)?;
-        let resp = client
-            .client
-            .get(url)
-            .header(DOCSPELL_AUTH, &token)
+        let resp = auth
+            .apply(client, client.client.get(url))?
             .send()
             .context(HttpSnafu { url })?;
-        if resp.status() == StatusCode::NOT_FOUND {
+        // on share routes, a 404 means the share is not accessible
+        let is_session = matches!(auth, DownloadAuth::Session { .. });
+        if is_session && resp.status() == StatusCode::NOT_FOUND {
             Ok(None)
         } else {
             Ok(Some(Download {
@@ -1078,12 +3203,9 @@ impl DownloadRef {
         }
     }
 
-    fn head_file(&self, client: &Client, token: &Option<String>, url: &str) -> Result<bool, Error> {
-        let token = session::session_token(token, client).context(SessionSnafu)?;
-        let resp = client
-            .client
-            .head(url)
-            .header(DOCSPELL_AUTH, &token)
+    fn head_file(&self, client: &Client, auth: &DownloadAuth, url: &str) -> Result<bool, Error> {
+        let resp = auth
+            .apply(client, client.client.head(url))?
             .send()
             .context(HttpSnafu { url })?;
         if resp.status() == StatusCode::NOT_FOUND {
@@ -1149,3 +3271,39 @@ impl Iterator for Downloads {
         self.refs.pop()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elements() -> Vec<(&'static str, &'static str)> {
+        vec![("AbC123", "Acme"), ("AbD456", "Bolt"), ("XyZ789", "abd456")]
+    }
+
+    fn find(key: &str) -> Result<(&'static str, &'static str), Error> {
+        find_unique("org", key, elements(), |e| (e.0, e.1))
+    }
+
+    #[test]
+    fn unit_find_unique_exact_id() {
+        assert_eq!(find("AbD456").unwrap(), ("AbD456", "Bolt"));
+    }
+
+    #[test]
+    fn unit_find_unique_name() {
+        assert_eq!(find("acme").unwrap(), ("AbC123", "Acme"));
+        assert_eq!(find("ABD456").unwrap(), ("XyZ789", "abd456"));
+    }
+
+    #[test]
+    fn unit_find_unique_prefix() {
+        assert_eq!(find("XyZ").unwrap(), ("XyZ789", "abd456"));
+        assert!(matches!(find("Ab"), Err(Error::NotUnique { .. })));
+    }
+
+    #[test]
+    fn unit_find_unique_not_found() {
+        assert!(matches!(find("nope"), Err(Error::NotFound { .. })));
+        assert!(matches!(find(""), Err(Error::NotFound { .. })));
+    }
+}
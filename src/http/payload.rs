@@ -1,11 +1,12 @@
 //! Defines payloads for requests and responses and their `De/Serialize` instances.
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Bookmark {
     pub id: String,
     pub name: String,
+    pub label: Option<String>,
     pub query: String,
     pub personal: bool,
     pub created: i64,
@@ -194,8 +195,11 @@ pub struct Source {
     pub enabled: bool,
     pub priority: String,
     pub folder: Option<String>,
+    #[serde(alias = "fileFilter")]
     pub file_filter: Option<String>,
     pub language: Option<String>,
+    #[serde(default, alias = "attachmentsOnly")]
+    pub attachments_only: bool,
     pub created: i64,
 }
 
@@ -448,3 +452,611 @@ impl SearchMode {
         }
     }
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Address {
+    pub street: String,
+    pub zip: String,
+    pub city: String,
+    pub country: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Contact {
+    pub id: String,
+    pub value: String,
+    pub kind: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Organization {
+    pub id: String,
+    pub name: String,
+    #[serde(alias = "shortName", rename(serialize = "shortName"))]
+    pub short_name: Option<String>,
+    pub address: Address,
+    pub contacts: Vec<Contact>,
+    pub notes: Option<String>,
+    #[serde(rename = "use")]
+    pub org_use: String,
+    pub created: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OrganizationList {
+    pub items: Vec<Organization>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Person {
+    pub id: String,
+    pub name: String,
+    pub organization: Option<IdName>,
+    pub address: Address,
+    pub contacts: Vec<Contact>,
+    pub notes: Option<String>,
+    #[serde(rename = "use")]
+    pub person_use: String,
+    pub created: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PersonList {
+    pub items: Vec<Person>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Equipment {
+    pub id: String,
+    pub name: String,
+    pub notes: Option<String>,
+    #[serde(rename = "use")]
+    pub equip_use: String,
+    pub created: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EquipmentList {
+    pub items: Vec<Equipment>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IdResult {
+    pub success: bool,
+    pub message: String,
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FolderItem {
+    pub id: String,
+    pub name: String,
+    pub owner: IdName,
+    pub created: i64,
+    #[serde(alias = "isMember")]
+    pub is_member: bool,
+    #[serde(alias = "memberCount")]
+    pub member_count: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FolderList {
+    pub items: Vec<FolderItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FolderDetail {
+    pub id: String,
+    pub name: String,
+    pub owner: IdName,
+    pub created: i64,
+    #[serde(alias = "isMember")]
+    pub is_member: bool,
+    #[serde(alias = "memberCount")]
+    pub member_count: u32,
+    pub members: Vec<IdName>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NewFolder {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct User {
+    pub id: String,
+    pub login: String,
+    pub state: String,
+    pub email: Option<String>,
+    pub created: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserList {
+    pub items: Vec<User>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomFieldDef {
+    pub id: String,
+    pub name: String,
+    pub label: Option<String>,
+    pub ftype: String,
+    pub usages: u32,
+    pub created: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomFieldDefList {
+    pub items: Vec<CustomFieldDef>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NewCustomField {
+    pub name: String,
+    pub label: Option<String>,
+    pub ftype: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SourceTagIn {
+    #[serde(serialize_with = "serialize_source_in")]
+    pub source: Source,
+    pub tags: Vec<String>,
+}
+
+/// Serializes the source as expected by the server, which wants some
+/// field names in camel case. `Source` keeps them in snake case to not
+/// change the output of `source list`; the server ignores these.
+fn serialize_source_in<S: Serializer>(source: &Source, serializer: S) -> Result<S::Ok, S::Error> {
+    #[derive(Serialize)]
+    struct SourceIn<'a> {
+        #[serde(flatten)]
+        source: &'a Source,
+        #[serde(rename = "fileFilter")]
+        file_filter: &'a Option<String>,
+        #[serde(rename = "attachmentsOnly")]
+        attachments_only: bool,
+    }
+    SourceIn {
+        source,
+        file_filter: &source.file_filter,
+        attachments_only: source.attachments_only,
+    }
+    .serialize(serializer)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OptionalText {
+    pub text: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OptionalDate {
+    pub date: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OptionalId {
+    pub id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DirectionValue {
+    pub direction: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EmptyTrashSetting {
+    pub schedule: String,
+    #[serde(alias = "minAge", rename(serialize = "minAge"))]
+    pub min_age: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IdList {
+    pub ids: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MoveAttachment {
+    pub source: String,
+    pub target: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AttachmentMeta {
+    pub content: String,
+    pub labels: Vec<Label>,
+    pub proposals: ItemProposals,
+    pub language: Option<String>,
+    pub pages: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Label {
+    pub label: String,
+    pub tag: String,
+    #[serde(alias = "startPosition", rename(serialize = "startPosition"))]
+    pub start_position: i32,
+    #[serde(alias = "endPosition", rename(serialize = "endPosition"))]
+    pub end_position: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ItemProposals {
+    #[serde(alias = "corrOrg", rename(serialize = "corrOrg"))]
+    pub corr_org: Vec<IdName>,
+    #[serde(alias = "corrPerson", rename(serialize = "corrPerson"))]
+    pub corr_person: Vec<IdName>,
+    #[serde(alias = "concPerson", rename(serialize = "concPerson"))]
+    pub conc_person: Vec<IdName>,
+    #[serde(alias = "concEquipment", rename(serialize = "concEquipment"))]
+    pub conc_equipment: Vec<IdName>,
+    #[serde(alias = "itemDate", rename(serialize = "itemDate"))]
+    pub item_date: Vec<i64>,
+    #[serde(alias = "dueDate", rename(serialize = "dueDate"))]
+    pub due_date: Vec<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AttachmentText {
+    pub id: String,
+    pub name: Option<String>,
+    pub content: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobQueueState {
+    pub progress: Vec<JobDetail>,
+    pub completed: Vec<JobDetail>,
+    pub queued: Vec<JobDetail>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobDetail {
+    pub id: String,
+    pub name: String,
+    pub submitted: i64,
+    pub priority: String,
+    pub state: String,
+    pub retries: u32,
+    pub progress: Option<u32>,
+    pub worker: Option<String>,
+    pub started: Option<i64>,
+    pub finished: Option<i64>,
+    #[serde(default)]
+    pub logs: Vec<JobLogEvent>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobLogEvent {
+    pub time: i64,
+    pub level: String,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShareDetail {
+    pub id: String,
+    pub query: String,
+    pub owner: IdName,
+    pub name: Option<String>,
+    pub enabled: bool,
+    #[serde(alias = "publishAt", rename(serialize = "publishAt"))]
+    pub publish_at: i64,
+    #[serde(alias = "publishUntil", rename(serialize = "publishUntil"))]
+    pub publish_until: i64,
+    pub expired: bool,
+    pub password: bool,
+    pub views: u32,
+    #[serde(alias = "lastAccess", rename(serialize = "lastAccess"))]
+    pub last_access: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShareList {
+    pub items: Vec<ShareDetail>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShareData {
+    pub name: Option<String>,
+    pub query: String,
+    pub enabled: bool,
+    pub password: Option<String>,
+    #[serde(alias = "publishUntil", rename(serialize = "publishUntil"))]
+    pub publish_until: i64,
+    #[serde(alias = "removePassword", rename(serialize = "removePassword"))]
+    pub remove_password: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShareSecret {
+    #[serde(alias = "shareId", rename(serialize = "shareId"))]
+    pub share_id: String,
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShareVerifyResult {
+    pub success: bool,
+    pub token: String,
+    #[serde(alias = "passwordRequired", rename(serialize = "passwordRequired"))]
+    pub password_required: bool,
+    pub message: String,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "channelType", rename_all = "lowercase")]
+pub enum NotificationChannel {
+    Mail(NotificationMail),
+    Gotify(NotificationGotify),
+    Matrix(NotificationMatrix),
+    Http(NotificationHttp),
+}
+impl NotificationChannel {
+    pub fn id(&self) -> &str {
+        match self {
+            NotificationChannel::Mail(c) => &c.id,
+            NotificationChannel::Gotify(c) => &c.id,
+            NotificationChannel::Matrix(c) => &c.id,
+            NotificationChannel::Http(c) => &c.id,
+        }
+    }
+
+    pub fn name(&self) -> Option<&String> {
+        match self {
+            NotificationChannel::Mail(c) => c.name.as_ref(),
+            NotificationChannel::Gotify(c) => c.name.as_ref(),
+            NotificationChannel::Matrix(c) => c.name.as_ref(),
+            NotificationChannel::Http(c) => c.name.as_ref(),
+        }
+    }
+
+    pub fn channel_type(&self) -> &'static str {
+        match self {
+            NotificationChannel::Mail(_) => "mail",
+            NotificationChannel::Gotify(_) => "gotify",
+            NotificationChannel::Matrix(_) => "matrix",
+            NotificationChannel::Http(_) => "http",
+        }
+    }
+
+    pub fn to_ref(&self) -> ChannelRef {
+        ChannelRef {
+            id: self.id().to_string(),
+            name: self.name().cloned(),
+            channel_type: self.channel_type().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NotificationMail {
+    #[serde(default)]
+    pub id: String,
+    pub name: Option<String>,
+    pub connection: String,
+    pub recipients: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NotificationGotify {
+    #[serde(default)]
+    pub id: String,
+    pub name: Option<String>,
+    pub url: String,
+    #[serde(alias = "appKey", rename(serialize = "appKey"))]
+    pub app_key: String,
+    pub priority: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NotificationMatrix {
+    #[serde(default)]
+    pub id: String,
+    pub name: Option<String>,
+    #[serde(alias = "homeServer", rename(serialize = "homeServer"))]
+    pub home_server: String,
+    #[serde(alias = "roomId", rename(serialize = "roomId"))]
+    pub room_id: String,
+    #[serde(alias = "accessToken", rename(serialize = "accessToken"))]
+    pub access_token: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NotificationHttp {
+    #[serde(default)]
+    pub id: String,
+    pub name: Option<String>,
+    pub url: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ChannelRef {
+    pub id: String,
+    pub name: Option<String>,
+    #[serde(alias = "channelType", rename(serialize = "channelType"))]
+    pub channel_type: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NotificationHook {
+    #[serde(default)]
+    pub id: String,
+    pub enabled: bool,
+    pub channels: Vec<ChannelRef>,
+    #[serde(alias = "allEvents", rename(serialize = "allEvents"))]
+    pub all_events: bool,
+    #[serde(alias = "eventFilter", rename(serialize = "eventFilter"))]
+    pub event_filter: Option<String>,
+    pub events: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NotificationChannelTestResult {
+    pub success: bool,
+    pub messages: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PeriodicQuerySettings {
+    #[serde(default)]
+    pub id: String,
+    pub enabled: bool,
+    pub summary: Option<String>,
+    pub channels: Vec<ChannelRef>,
+    pub schedule: String,
+    pub query: Option<String>,
+    pub bookmark: Option<String>,
+    #[serde(alias = "contentStart", rename(serialize = "contentStart"))]
+    pub content_start: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EmailSettings {
+    pub name: String,
+    #[serde(alias = "smtpHost", rename(serialize = "smtpHost"))]
+    pub smtp_host: String,
+    #[serde(alias = "smtpPort", rename(serialize = "smtpPort"))]
+    pub smtp_port: Option<u16>,
+    #[serde(alias = "smtpUser", rename(serialize = "smtpUser"))]
+    pub smtp_user: Option<String>,
+    #[serde(
+        alias = "smtpPassword",
+        rename(serialize = "smtpPassword"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub smtp_password: Option<String>,
+    pub from: String,
+    #[serde(alias = "replyTo", rename(serialize = "replyTo"))]
+    pub reply_to: Option<String>,
+    #[serde(alias = "sslType", rename(serialize = "sslType"))]
+    pub ssl_type: String,
+    #[serde(alias = "ignoreCertificates", rename(serialize = "ignoreCertificates"))]
+    pub ignore_certificates: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EmailSettingsList {
+    pub items: Vec<EmailSettings>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ImapSettings {
+    pub name: String,
+    #[serde(alias = "imapHost", rename(serialize = "imapHost"))]
+    pub imap_host: String,
+    #[serde(alias = "imapPort", rename(serialize = "imapPort"))]
+    pub imap_port: Option<u16>,
+    #[serde(alias = "imapUser", rename(serialize = "imapUser"))]
+    pub imap_user: Option<String>,
+    #[serde(
+        alias = "imapPassword",
+        rename(serialize = "imapPassword"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub imap_password: Option<String>,
+    #[serde(alias = "sslType", rename(serialize = "sslType"))]
+    pub ssl_type: String,
+    #[serde(alias = "ignoreCertificates", rename(serialize = "ignoreCertificates"))]
+    pub ignore_certificates: bool,
+    #[serde(alias = "useOAuth", rename(serialize = "useOAuth"))]
+    pub use_oauth: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ImapSettingsList {
+    pub items: Vec<ImapSettings>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScanMailboxSettings {
+    #[serde(default)]
+    pub id: String,
+    pub enabled: bool,
+    pub summary: Option<String>,
+    #[serde(alias = "imapConnection", rename(serialize = "imapConnection"))]
+    pub imap_connection: String,
+    pub schedule: String,
+    pub folders: Vec<String>,
+    #[serde(alias = "receivedSinceHours", rename(serialize = "receivedSinceHours"))]
+    pub received_since_hours: Option<u32>,
+    #[serde(alias = "targetFolder", rename(serialize = "targetFolder"))]
+    pub target_folder: Option<String>,
+    #[serde(alias = "deleteMail", rename(serialize = "deleteMail"))]
+    pub delete_mail: bool,
+    pub direction: Option<String>,
+    #[serde(alias = "itemFolder", rename(serialize = "itemFolder"))]
+    pub item_folder: Option<String>,
+    #[serde(alias = "fileFilter", rename(serialize = "fileFilter"))]
+    pub file_filter: Option<String>,
+    pub tags: Option<StringList>,
+    #[serde(alias = "subjectFilter", rename(serialize = "subjectFilter"))]
+    pub subject_filter: Option<String>,
+    pub language: Option<String>,
+    #[serde(alias = "postHandleAll", rename(serialize = "postHandleAll"))]
+    pub post_handle_all: Option<bool>,
+    #[serde(alias = "attachmentsOnly", rename(serialize = "attachmentsOnly"))]
+    pub attachments_only: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimpleMail {
+    pub recipients: Vec<String>,
+    pub cc: Vec<String>,
+    pub bcc: Vec<String>,
+    pub subject: String,
+    pub body: String,
+    #[serde(alias = "addAllAttachments", rename(serialize = "addAllAttachments"))]
+    pub add_all_attachments: bool,
+    #[serde(alias = "attachmentIds", rename(serialize = "attachmentIds"))]
+    pub attachment_ids: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SentMail {
+    pub id: String,
+    pub sender: String,
+    pub connection: String,
+    pub recipients: Vec<String>,
+    pub subject: String,
+    pub body: String,
+    pub created: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SentMails {
+    pub items: Vec<SentMail>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_source_tag_in_json() {
+        let data = SourceTagIn {
+            source: Source {
+                id: "".into(),
+                abbrev: "test".into(),
+                description: None,
+                counter: 0,
+                enabled: true,
+                priority: "low".into(),
+                folder: None,
+                file_filter: Some("*.pdf".into()),
+                language: None,
+                attachments_only: true,
+                created: 0,
+            },
+            tags: vec![],
+        };
+        let json = serde_json::to_value(&data).unwrap();
+        assert_eq!(json["source"]["fileFilter"], "*.pdf");
+        assert_eq!(json["source"]["attachmentsOnly"], true);
+        assert_eq!(json["source"]["abbrev"], "test");
+    }
+}
@@ -1,7 +1,21 @@
 pub const DOCSPELL_AUTH: &str = "X-Docspell-Auth";
 pub const DOCSPELL_ADMIN: &str = "Docspell-Admin-Secret";
+pub const DOCSPELL_SHARE_AUTH: &str = "Docspell-Share-Auth";
 
-use percent_encoding::percent_decode;
+use percent_encoding::{percent_decode, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+/// Characters to encode in a single path segment of an url.
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// Encodes the given value to be used as a single path segment in an
+/// url.
+pub fn encode_path_segment(value: &str) -> String {
+    utf8_percent_encode(value, PATH_SEGMENT).to_string()
+}
 
 // Couldn't find a library for parsing the header properly ¯\_(ツ)_/¯
 
@@ -46,6 +60,14 @@ fn from_percent_encoded(v: &str) -> Option<(u32, String)> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn unit_encode_path_segment() {
+        assert_eq!(encode_path_segment("gmail"), "gmail");
+        assert_eq!(encode_path_segment("my-mail_1.0~x"), "my-mail_1.0~x");
+        assert_eq!(encode_path_segment("a b/c?d#e"), "a%20b%2Fc%3Fd%23e");
+        assert_eq!(encode_path_segment("Büro"), "B%C3%BCro");
+    }
+
     #[test]
     fn unit_filename_from_header() {
         assert_eq!(
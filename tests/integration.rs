@@ -2,7 +2,10 @@ mod common;
 
 use crate::common::{mk_cmd, Result};
 use assert_cmd::prelude::*;
-use dsc::http::payload::{BasicResult, ItemDetail, SearchResult, SourceAndTags, Summary};
+use dsc::http::payload::{
+    AttachmentMeta, AttachmentText, BasicResult, CustomFieldDef, ItemDetail, ItemProposals,
+    JobQueueState, Organization, Person, SearchResult, SourceAndTags, Summary, Tag,
+};
 use std::fs;
 use std::{io::Write, path::Path, process::Command};
 
@@ -296,6 +299,83 @@ fn remote_download_zip() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn remote_download_preview() -> Result<()> {
+    let mut cmd = mk_cmd()?;
+    let out = cmd
+        .arg("download")
+        .arg("--target")
+        .arg("preview_test")
+        .arg("--preview")
+        .arg("date<today")
+        .assert();
+
+    out.success().stderr("");
+    let files = std::fs::read_dir("preview_test/").unwrap().count();
+    assert_eq!(files, 2);
+
+    std::fs::remove_dir_all("preview_test/").unwrap();
+    Ok(())
+}
+
+#[test]
+fn remote_item_preview() -> Result<()> {
+    std::fs::create_dir_all("item_preview_test")?;
+    let mut cmd = mk_cmd()?;
+    let out = cmd
+        .arg("item")
+        .arg("preview")
+        .arg(&ITEM_ID2[0..7])
+        .arg("--output")
+        .arg("item_preview_test/preview.img")
+        .assert();
+
+    out.success().stderr("");
+    let file = std::path::PathBuf::from("item_preview_test/preview.img");
+    assert!(file.exists());
+
+    let mut cmd = mk_cmd()?;
+    let out = cmd
+        .arg("item")
+        .arg("preview")
+        .arg(&ITEM_ID2[0..7])
+        .arg("--output")
+        .arg("item_preview_test/preview.img")
+        .assert();
+    out.failure().stdout("");
+
+    std::fs::remove_dir_all("item_preview_test/").unwrap();
+    Ok(())
+}
+
+#[test]
+fn remote_queue_state() -> Result<()> {
+    let mut cmd = mk_cmd()?;
+    let out = cmd.arg("queue").arg("state").output()?;
+    let _state: JobQueueState = serde_json::from_slice(out.stdout.as_slice())?;
+    out.assert().success().stderr("");
+
+    let mut cmd = mk_cmd()?;
+    let out = cmd.arg("queue").arg("state").arg("--active").output()?;
+    let state: JobQueueState = serde_json::from_slice(out.stdout.as_slice())?;
+    out.assert().success().stderr("");
+    assert!(state.completed.is_empty());
+    Ok(())
+}
+
+#[test]
+fn remote_queue_cancel_unknown() -> Result<()> {
+    let mut cmd = mk_cmd()?;
+    let out = cmd
+        .arg("queue")
+        .arg("cancel")
+        .arg("dsc-test-unknown-job")
+        .assert();
+
+    out.failure().stdout("");
+    Ok(())
+}
+
 #[test]
 fn remote_admin_convert_all_pdfs() -> Result<()> {
     let mut cmd = mk_cmd()?;
@@ -321,6 +401,53 @@ fn remote_item_get() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn remote_item_text() -> Result<()> {
+    let mut cmd = mk_cmd()?;
+    let out = cmd.arg("item").arg("text").arg(&ITEM_ID2[0..7]).output()?;
+    let texts: Vec<AttachmentText> = serde_json::from_slice(out.stdout.as_slice())?;
+    out.assert().success().stderr("");
+
+    let mut cmd = mk_cmd()?;
+    let out = cmd.arg("item").arg("get").arg(ITEM_ID2).output()?;
+    let item: ItemDetail = serde_json::from_slice(out.stdout.as_slice())?;
+    let ids: Vec<&str> = texts.iter().map(|t| t.id.as_str()).collect();
+    let expected: Vec<&str> = item.attachments.iter().map(|a| a.id.as_str()).collect();
+    assert_eq!(ids, expected);
+    Ok(())
+}
+
+#[test]
+fn remote_attachment_meta() -> Result<()> {
+    let mut cmd = mk_cmd()?;
+    let out = cmd.arg("item").arg("get").arg(ITEM_ID2).output()?;
+    let item: ItemDetail = serde_json::from_slice(out.stdout.as_slice())?;
+
+    let mut cmd = mk_cmd()?;
+    let out = cmd
+        .arg("attachment")
+        .arg("meta")
+        .arg(&item.attachments[0].id)
+        .output()?;
+    let meta: AttachmentMeta = serde_json::from_slice(out.stdout.as_slice())?;
+    out.assert().success().stderr("");
+    assert!(!meta.content.is_empty());
+    Ok(())
+}
+
+#[test]
+fn remote_item_proposals() -> Result<()> {
+    let mut cmd = mk_cmd()?;
+    let out = cmd
+        .arg("item")
+        .arg("proposals")
+        .arg(&ITEM_ID2[0..7])
+        .output()?;
+    let _proposals: ItemProposals = serde_json::from_slice(out.stdout.as_slice())?;
+    out.assert().success().stderr("");
+    Ok(())
+}
+
 #[test]
 fn remote_item_tags_add() -> Result<()> {
     let mut cmd = mk_cmd()?;
@@ -435,3 +562,75 @@ fn remote_item_fields_set_bad_value() -> Result<()> {
     ));
     Ok(())
 }
+
+#[test]
+fn remote_org_list() -> Result<()> {
+    let mut cmd = mk_cmd()?;
+    let out = cmd.arg("org").arg("list").output()?;
+
+    let out: Vec<Organization> = serde_json::from_slice(out.stdout.as_slice())?;
+    assert_eq!(out.len(), 3);
+    Ok(())
+}
+
+#[test]
+fn remote_org_list_filter_name() -> Result<()> {
+    let mut cmd = mk_cmd()?;
+    let out = cmd
+        .arg("org")
+        .arg("list")
+        .arg("--name")
+        .arg("pancake")
+        .output()?;
+
+    let out: Vec<Organization> = serde_json::from_slice(out.stdout.as_slice())?;
+    assert_eq!(out.len(), 1);
+    assert_eq!(out[0].name, "Pancake Company");
+    Ok(())
+}
+
+#[test]
+fn remote_person_list() -> Result<()> {
+    let mut cmd = mk_cmd()?;
+    let out = cmd.arg("person").arg("list").output()?;
+
+    let out: Vec<Person> = serde_json::from_slice(out.stdout.as_slice())?;
+    assert_eq!(out.len(), 2);
+    Ok(())
+}
+
+#[test]
+fn remote_field_list() -> Result<()> {
+    let mut cmd = mk_cmd()?;
+    let out = cmd.arg("field").arg("list").output()?;
+
+    let out: Vec<CustomFieldDef> = serde_json::from_slice(out.stdout.as_slice())?;
+    assert_eq!(out.len(), 3);
+    Ok(())
+}
+
+#[test]
+fn remote_tag_list() -> Result<()> {
+    let mut cmd = mk_cmd()?;
+    let out = cmd.arg("tag").arg("list").output()?;
+
+    let out: Vec<Tag> = serde_json::from_slice(out.stdout.as_slice())?;
+    assert_eq!(out.len(), 5);
+    Ok(())
+}
+
+#[test]
+fn remote_tag_list_category() -> Result<()> {
+    let mut cmd = mk_cmd()?;
+    let out = cmd
+        .arg("tag")
+        .arg("list")
+        .arg("--category")
+        .arg("state")
+        .output()?;
+
+    let out: Vec<Tag> = serde_json::from_slice(out.stdout.as_slice())?;
+    assert_eq!(out.len(), 1);
+    assert_eq!(out[0].name, "Todo");
+    Ok(())
+}
@@ -0,0 +1,693 @@
+//! Tests that change data on the server.
+//!
+//! These are kept apart from `integration.rs`, because test binaries
+//! run one after another. Creating entities here must not interfere
+//! with the tests there that count them. Each test uses its own
+//! names and removes what it created. Tests in this file run in
+//! parallel, so all tests using the fixture items hold a lock on
+//! them.
+mod common;
+
+use crate::common::{mk_cmd, Result};
+use assert_cmd::prelude::*;
+use dsc::http::payload::{
+    BasicResult, BookmarkList, CustomFieldDef, EmailSettingsList, Equipment, FolderItem, IdResult,
+    ImapSettingsList, ItemDetail, NotificationChannel, NotificationHook, Organization,
+    PeriodicQuerySettings, Person, ScanMailboxSettings, SearchResult, SentMails, ShareList,
+    SourceAndTags, Tag,
+};
+use serde::de::DeserializeOwned;
+use std::sync::{Mutex, MutexGuard};
+
+const ITEM_ID1: &str = "2wKtSUVt3Kj-mAmexmm1jFe-BU6aY6PN4vo-5cpaDD2EyRm";
+const ITEM_ID2: &str = "J4wAkg3jxt5-7QaYXD1WTmF-gq4kGaS89RP-DnPyUwa77fK";
+
+static ITEMS: Mutex<()> = Mutex::new(());
+
+/// Locks the fixture items until the returned guard is dropped. A
+/// failed test doesn't block the others.
+fn lock_items() -> MutexGuard<'static, ()> {
+    ITEMS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Runs dsc with the given arguments and parses its json output.
+fn run<T: DeserializeOwned>(args: &[&str]) -> Result<T> {
+    let out = mk_cmd()?.args(args).output()?;
+    let result: T = serde_json::from_slice(out.stdout.as_slice())?;
+    out.assert().success();
+    Ok(result)
+}
+
+/// Runs dsc with the given arguments and expects a successful result.
+fn run_ok(args: &[&str]) -> Result<()> {
+    let result: BasicResult = run(args)?;
+    assert!(result.success, "{}: {}", args.join(" "), result.message);
+    Ok(())
+}
+
+/// Runs dsc with the given arguments and expects only successful
+/// results.
+fn run_all_ok(args: &[&str]) -> Result<()> {
+    let results: Vec<BasicResult> = run(args)?;
+    assert!(!results.is_empty(), "{}: no results", args.join(" "));
+    for result in results {
+        assert!(result.success, "{}: {}", args.join(" "), result.message);
+    }
+    Ok(())
+}
+
+#[test]
+fn remote_org_add_update_delete() -> Result<()> {
+    run_ok(&["org", "add", "--name", "dsc-test-org"])?;
+    run_ok(&[
+        "org",
+        "update",
+        "--id",
+        "dsc-test-org",
+        "--short-name",
+        "dtorg",
+    ])?;
+    let orgs: Vec<Organization> = run(&["org", "list", "--name", "dsc-test-org"])?;
+    assert_eq!(orgs.len(), 1);
+    assert_eq!(orgs[0].short_name.as_deref(), Some("dtorg"));
+
+    run_ok(&["org", "delete", "dsc-test-org"])?;
+    Ok(())
+}
+
+#[test]
+fn remote_person_add_update_delete() -> Result<()> {
+    run_ok(&[
+        "person",
+        "add",
+        "--name",
+        "dsc-test-person",
+        "--contact",
+        "email:person@example.com",
+    ])?;
+    run_ok(&[
+        "person",
+        "update",
+        "--id",
+        "dsc-test-person",
+        "--contact",
+        "phone:0123",
+    ])?;
+    let persons: Vec<Person> = run(&["person", "list", "--name", "dsc-test-person"])?;
+    assert_eq!(persons.len(), 1);
+    let contacts: Vec<(&str, &str)> = persons[0]
+        .contacts
+        .iter()
+        .map(|c| (c.kind.as_str(), c.value.as_str()))
+        .collect();
+    assert_eq!(contacts, vec![("phone", "0123")]);
+
+    run_ok(&["person", "delete", "dsc-test-person"])?;
+    Ok(())
+}
+
+#[test]
+fn remote_equipment_add_update_delete() -> Result<()> {
+    run_ok(&["equipment", "add", "--name", "dsc-test-equipment"])?;
+    run_ok(&[
+        "equipment",
+        "update",
+        "--id",
+        "dsc-test-equipment",
+        "--notes",
+        "updated",
+    ])?;
+    let equips: Vec<Equipment> = run(&["equipment", "list", "--name", "dsc-test-equipment"])?;
+    assert_eq!(equips.len(), 1);
+    assert_eq!(equips[0].notes.as_deref(), Some("updated"));
+
+    run_ok(&["equipment", "delete", "dsc-test-equipment"])?;
+    Ok(())
+}
+
+#[test]
+fn remote_folder_add_rename_delete() -> Result<()> {
+    run_ok(&["folder", "add", "--name", "dsc-test-folder"])?;
+    run_ok(&[
+        "folder",
+        "rename",
+        "--id",
+        "dsc-test-folder",
+        "--name",
+        "dsc-test-folder-renamed",
+    ])?;
+    let folders: Vec<FolderItem> = run(&["folder", "list", "--name", "dsc-test-folder"])?;
+    let names: Vec<&str> = folders.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, vec!["dsc-test-folder-renamed"]);
+
+    run_ok(&["folder", "delete", "dsc-test-folder-renamed"])?;
+    Ok(())
+}
+
+#[test]
+fn remote_field_add_update_delete() -> Result<()> {
+    run_ok(&[
+        "field",
+        "add",
+        "--name",
+        "dsc-test-field",
+        "--ftype",
+        "text",
+    ])?;
+    run_ok(&[
+        "field",
+        "update",
+        "--id",
+        "dsc-test-field",
+        "--label",
+        "Test Field",
+        "--ftype",
+        "numeric",
+    ])?;
+    let fields: Vec<CustomFieldDef> = run(&["field", "list", "--name", "dsc-test-field"])?;
+    assert_eq!(fields.len(), 1);
+    assert_eq!(fields[0].label.as_deref(), Some("Test Field"));
+    assert_eq!(fields[0].ftype, "numeric");
+
+    run_ok(&["field", "delete", "dsc-test-field"])?;
+    Ok(())
+}
+
+#[test]
+fn remote_tag_add_update_delete() -> Result<()> {
+    run_ok(&["tag", "add", "--name", "dsc-test-tag", "--category", "test"])?;
+    run_ok(&["tag", "update", "--id", "dsc-test-tag", "--remove-category"])?;
+    let tags: Vec<Tag> = run(&["tag", "list", "--name", "dsc-test-tag"])?;
+    assert_eq!(tags.len(), 1);
+    assert_eq!(tags[0].category, None);
+
+    run_ok(&["tag", "delete", "dsc-test-tag"])?;
+    Ok(())
+}
+
+#[test]
+fn remote_tag_merge() -> Result<()> {
+    let _items = lock_items();
+    run_ok(&["tag", "add", "--name", "dsc-test-merge-from"])?;
+    run_ok(&["tag", "add", "--name", "dsc-test-merge-to"])?;
+    run_ok(&[
+        "item",
+        "tags",
+        "--id",
+        ITEM_ID1,
+        "--add",
+        "dsc-test-merge-from",
+    ])?;
+
+    run_ok(&[
+        "tag",
+        "merge",
+        "--from",
+        "dsc-test-merge-from",
+        "--to",
+        "dsc-test-merge-to",
+        "--batch-size",
+        "1",
+    ])?;
+    let item: ItemDetail = run(&["item", "get", ITEM_ID1])?;
+    let tag_names: Vec<String> = item.tags.into_iter().map(|t| t.name).collect();
+    assert!(tag_names.contains(&"dsc-test-merge-to".to_string()));
+    assert!(!tag_names.contains(&"dsc-test-merge-from".to_string()));
+    let tags: Vec<Tag> = run(&["tag", "list", "--name", "dsc-test-merge-from"])?;
+    assert!(tags.is_empty());
+
+    run_ok(&[
+        "item",
+        "tags",
+        "--id",
+        ITEM_ID1,
+        "--remove",
+        "dsc-test-merge-to",
+    ])?;
+    run_ok(&["tag", "delete", "dsc-test-merge-to"])?;
+    Ok(())
+}
+
+#[test]
+fn remote_source_add_update_delete() -> Result<()> {
+    run_ok(&[
+        "source",
+        "add",
+        "--name",
+        "dsc-test-source",
+        "--file-filter",
+        "*.pdf",
+    ])?;
+    run_ok(&[
+        "source",
+        "update",
+        "--id",
+        "dsc-test-source",
+        "--attachments-only",
+        "true",
+    ])?;
+    run_ok(&["source", "disable", "dsc-test-source"])?;
+
+    let out = mk_cmd()?
+        .args(&["source", "list", "--name", "dsc-test-source"])
+        .output()?;
+    let json: serde_json::Value = serde_json::from_slice(out.stdout.as_slice())?;
+    assert_eq!(json[0]["source"]["file_filter"], "*.pdf");
+    let sources: Vec<SourceAndTags> = serde_json::from_value(json)?;
+    assert_eq!(sources.len(), 1);
+    assert!(sources[0].source.attachments_only);
+    assert!(!sources[0].source.enabled);
+
+    run_ok(&["source", "delete", "dsc-test-source"])?;
+    Ok(())
+}
+
+#[test]
+fn remote_bookmark_add_update_run_delete() -> Result<()> {
+    run_ok(&[
+        "bookmark",
+        "add",
+        "--name",
+        "dsc-test-bookmark",
+        "--query",
+        "corr:pancake*",
+    ])?;
+    run_ok(&[
+        "bookmark",
+        "update",
+        "--id",
+        "dsc-test-bookmark",
+        "--label",
+        "Pancakes",
+    ])?;
+    let list: BookmarkList = run(&["bookmark", "get"])?;
+    let bookmark = list
+        .bookmarks
+        .iter()
+        .find(|b| b.name == "dsc-test-bookmark")
+        .expect("bookmark not found");
+    assert_eq!(bookmark.label.as_deref(), Some("Pancakes"));
+
+    let result: SearchResult = run(&["bookmark", "run", "dsc-test-bookmark"])?;
+    assert_eq!(result.groups.len(), 1);
+
+    run_ok(&["bookmark", "delete", "dsc-test-bookmark"])?;
+    Ok(())
+}
+
+#[test]
+fn remote_item_set() -> Result<()> {
+    let _items = lock_items();
+    let before: ItemDetail = run(&["item", "get", ITEM_ID1])?;
+    let (changed, restore) = if before.direction == "outgoing" {
+        ("in", "out")
+    } else {
+        ("out", "in")
+    };
+    run_all_ok(&["item", "set", "--id", ITEM_ID1, "--direction", changed])?;
+    run_all_ok(&[
+        "item",
+        "set",
+        "--id",
+        &ITEM_ID1[0..7],
+        "--name",
+        "dsc-test-item",
+    ])?;
+
+    let after: ItemDetail = run(&["item", "get", ITEM_ID1])?;
+    assert_ne!(after.direction, before.direction);
+    assert_eq!(after.name, "dsc-test-item");
+
+    run_all_ok(&["item", "set", "--id", ITEM_ID1, "--direction", restore])?;
+    run_all_ok(&["item", "set", "--id", ITEM_ID1, "--name", &before.name])?;
+    Ok(())
+}
+
+#[test]
+fn remote_item_notes() -> Result<()> {
+    let _items = lock_items();
+    let before: ItemDetail = run(&["item", "get", ITEM_ID1])?;
+    run_ok(&["item", "notes", ITEM_ID1, "--set", "dsc-test-notes"])?;
+    run_ok(&["item", "notes", &ITEM_ID1[0..7], "--append", "appended"])?;
+    let item: ItemDetail = run(&["item", "get", ITEM_ID1])?;
+    assert_eq!(item.notes.as_deref(), Some("dsc-test-notes\nappended"));
+
+    let out = assert_cmd::Command::from_std(mk_cmd()?)
+        .args(&["item", "notes", ITEM_ID1, "--stdin"])
+        .write_stdin("from stdin")
+        .output()?;
+    let result: BasicResult = serde_json::from_slice(out.stdout.as_slice())?;
+    assert!(result.success, "{}", result.message);
+    let item: ItemDetail = run(&["item", "get", ITEM_ID1])?;
+    assert_eq!(item.notes.as_deref(), Some("from stdin"));
+
+    let notes = before.notes.unwrap_or_default();
+    run_ok(&["item", "notes", ITEM_ID1, "--set", &notes])?;
+    Ok(())
+}
+
+#[test]
+fn remote_item_confirm_delete_restore() -> Result<()> {
+    let _items = lock_items();
+    let before: ItemDetail = run(&["item", "get", ITEM_ID2])?;
+    run_all_ok(&["item", "unconfirm", ITEM_ID2])?;
+    let item: ItemDetail = run(&["item", "get", ITEM_ID2])?;
+    assert_eq!(item.state, "created");
+    run_all_ok(&["item", "confirm", &ITEM_ID2[0..7]])?;
+    let item: ItemDetail = run(&["item", "get", ITEM_ID2])?;
+    assert_eq!(item.state, "confirmed");
+
+    run_all_ok(&["item", "delete", ITEM_ID2])?;
+    let item: ItemDetail = run(&["item", "get", ITEM_ID2])?;
+    assert_eq!(item.state, "deleted");
+    run_all_ok(&["item", "restore", ITEM_ID2])?;
+    let item: ItemDetail = run(&["item", "get", ITEM_ID2])?;
+    assert_ne!(item.state, "deleted");
+
+    let restore = if before.state == "confirmed" {
+        "confirm"
+    } else {
+        "unconfirm"
+    };
+    run_all_ok(&["item", restore, ITEM_ID2])?;
+    Ok(())
+}
+
+#[test]
+fn remote_item_merge_into_itself() -> Result<()> {
+    let _items = lock_items();
+    let mut cmd = mk_cmd()?;
+    let out = cmd
+        .args(&["item", "merge", "--yes", ITEM_ID1])
+        .arg(&ITEM_ID1[0..7])
+        .assert();
+    out.failure().stdout("");
+
+    let item: ItemDetail = run(&["item", "get", ITEM_ID1])?;
+    assert_eq!(item.id, ITEM_ID1);
+    Ok(())
+}
+
+#[test]
+fn remote_attachment_rename() -> Result<()> {
+    let _items = lock_items();
+    let item: ItemDetail = run(&["item", "get", ITEM_ID1])?;
+    let attach = &item.attachments[0];
+    run_ok(&[
+        "attachment",
+        "rename",
+        &attach.id,
+        "--name",
+        "dsc-test-attachment.pdf",
+    ])?;
+
+    let changed: ItemDetail = run(&["item", "get", ITEM_ID1])?;
+    assert_eq!(
+        changed.attachments[0].name.as_deref(),
+        Some("dsc-test-attachment.pdf")
+    );
+
+    match &attach.name {
+        Some(name) => run_ok(&["attachment", "rename", &attach.id, "--name", name])?,
+        None => run_ok(&["attachment", "rename", &attach.id, "--clear"])?,
+    }
+    Ok(())
+}
+
+#[test]
+fn remote_item_reprocess() -> Result<()> {
+    let _items = lock_items();
+    let item: ItemDetail = run(&["item", "get", ITEM_ID1])?;
+    run_all_ok(&[
+        "item",
+        "reprocess",
+        &ITEM_ID1[0..7],
+        "--attachment",
+        &item.attachments[0].id,
+    ])?;
+
+    let query = format!("id:{}", ITEM_ID2);
+    run_ok(&["item", "reprocess", "--query", &query])?;
+    Ok(())
+}
+
+#[test]
+fn remote_share_create_update_delete() -> Result<()> {
+    let created: IdResult = run(&[
+        "share",
+        "create",
+        "--query",
+        "name:*",
+        "--name",
+        "dsc-test-share",
+        "--password",
+        "test",
+    ])?;
+    assert!(created.success, "{}", created.message);
+    run_ok(&[
+        "share",
+        "update",
+        "--id",
+        &created.id,
+        "--remove-password",
+        "--enabled",
+        "false",
+    ])?;
+    let shares: ShareList = run(&["share", "list", "--name", "dsc-test-share"])?;
+    assert_eq!(shares.items.len(), 1);
+    assert!(!shares.items[0].enabled);
+    assert!(!shares.items[0].password);
+
+    run_ok(&["share", "delete", &created.id])?;
+    Ok(())
+}
+
+#[test]
+fn remote_share_fetch() -> Result<()> {
+    let _items = lock_items();
+    let created: IdResult = run(&[
+        "share",
+        "create",
+        "--query",
+        "corr:pancake*",
+        "--name",
+        "dsc-test-fetch",
+    ])?;
+    assert!(created.success, "{}", created.message);
+
+    let result: SearchResult = run(&["share", "fetch", &created.id, "--list"])?;
+    assert_eq!(result.groups.len(), 1);
+
+    let target = std::path::Path::new("target/test_share_fetch");
+    if target.exists() {
+        std::fs::remove_dir_all(target)?;
+    }
+    let mut cmd = mk_cmd()?;
+    cmd.args(&["share", "fetch", &created.id, "--target"])
+        .arg(target)
+        .assert()
+        .success();
+    assert_eq!(std::fs::read_dir(target)?.count(), 1);
+    std::fs::remove_dir_all(target)?;
+
+    run_ok(&["share", "delete", &created.id])?;
+    Ok(())
+}
+
+#[test]
+fn remote_notify_channel_and_hook() -> Result<()> {
+    run_all_ok(&[
+        "notify",
+        "channel",
+        "add",
+        "http",
+        "--name",
+        "dsc-test-channel",
+        "--url",
+        "http://localhost:1/dsc-test",
+    ])?;
+    let channels: Vec<NotificationChannel> = run(&["notify", "channel", "list"])?;
+    assert!(channels
+        .iter()
+        .any(|c| c.name().map(String::as_str) == Some("dsc-test-channel")));
+
+    run_ok(&[
+        "notify",
+        "hook",
+        "add",
+        "--channel",
+        "dsc-test-channel",
+        "--all-events",
+    ])?;
+    let hooks: Vec<NotificationHook> = run(&["notify", "hook", "list"])?;
+    let hook = hooks
+        .iter()
+        .find(|h| {
+            h.channels
+                .iter()
+                .any(|c| c.name.as_deref() == Some("dsc-test-channel"))
+        })
+        .expect("hook not found");
+    assert!(hook.all_events);
+
+    run_ok(&["notify", "hook", "delete", &hook.id])?;
+    run_ok(&["notify", "channel", "delete", "dsc-test-channel"])?;
+    Ok(())
+}
+
+#[test]
+fn remote_periodic_query_add_update_delete() -> Result<()> {
+    run_all_ok(&[
+        "notify",
+        "channel",
+        "add",
+        "http",
+        "--name",
+        "dsc-test-pq-channel",
+        "--url",
+        "http://localhost:1/dsc-test",
+    ])?;
+    run_ok(&[
+        "periodic-query",
+        "add",
+        "--summary",
+        "dsc-test-pq",
+        "--schedule",
+        "*-*-* 01:00",
+        "--query",
+        "name:*",
+        "--channel",
+        "dsc-test-pq-channel",
+    ])?;
+    run_ok(&[
+        "periodic-query",
+        "update",
+        "--id",
+        "dsc-test-pq",
+        "--enabled",
+        "false",
+    ])?;
+    let tasks: Vec<PeriodicQuerySettings> = run(&["periodic-query", "list"])?;
+    let task = tasks
+        .iter()
+        .find(|t| t.summary.as_deref() == Some("dsc-test-pq"))
+        .expect("periodic query not found");
+    assert!(!task.enabled);
+    assert_eq!(task.query.as_deref(), Some("name:*"));
+
+    run_ok(&["periodic-query", "delete", "dsc-test-pq"])?;
+    run_ok(&["notify", "channel", "delete", "dsc-test-pq-channel"])?;
+    Ok(())
+}
+
+#[test]
+fn remote_mail_settings_smtp() -> Result<()> {
+    run_ok(&[
+        "mail-settings",
+        "smtp",
+        "add",
+        "--name",
+        "dsc-test-smtp",
+        "--host",
+        "localhost",
+        "--user",
+        "dsc",
+        "--password",
+        "secret",
+        "--from",
+        "dsc@example.com",
+    ])?;
+    let out = mk_cmd()?
+        .args(&["mail-settings", "smtp", "list", "--name", "dsc-test-smtp"])
+        .output()?;
+    let settings: EmailSettingsList = serde_json::from_slice(out.stdout.as_slice())?;
+    assert_eq!(settings.items.len(), 1);
+    assert!(!String::from_utf8_lossy(&out.stdout).contains("secret"));
+
+    run_ok(&["mail-settings", "smtp", "delete", "dsc-test-smtp"])?;
+    Ok(())
+}
+
+#[test]
+fn remote_scan_mailbox_add_update_delete() -> Result<()> {
+    run_ok(&[
+        "mail-settings",
+        "imap",
+        "add",
+        "--name",
+        "dsc-test-imap",
+        "--host",
+        "localhost",
+        "--user",
+        "dsc",
+        "--password",
+        "secret",
+    ])?;
+    let imap: ImapSettingsList =
+        run(&["mail-settings", "imap", "list", "--name", "dsc-test-imap"])?;
+    assert_eq!(imap.items.len(), 1);
+    assert_eq!(imap.items[0].imap_password, None);
+
+    run_ok(&[
+        "scan-mailbox",
+        "add",
+        "--summary",
+        "dsc-test-scan",
+        "--imap-connection",
+        "dsc-test-imap",
+        "--schedule",
+        "*-*-* 01:00",
+        "--folder",
+        "INBOX",
+        "--target-folder",
+        "Done",
+        "--language",
+        "deu",
+    ])?;
+    run_ok(&[
+        "scan-mailbox",
+        "update",
+        "--id",
+        "dsc-test-scan",
+        "--remove-target-folder",
+        "--remove-language",
+        "--attachments-only",
+        "true",
+    ])?;
+    let tasks: Vec<ScanMailboxSettings> = run(&["scan-mailbox", "list"])?;
+    let task = tasks
+        .iter()
+        .find(|t| t.summary.as_deref() == Some("dsc-test-scan"))
+        .expect("scan-mailbox task not found");
+    assert_eq!(task.target_folder, None);
+    assert_eq!(task.language, None);
+    assert_eq!(task.attachments_only, Some(true));
+
+    run_ok(&["scan-mailbox", "delete", "dsc-test-scan"])?;
+    run_ok(&["mail-settings", "imap", "delete", "dsc-test-imap"])?;
+    Ok(())
+}
+
+#[test]
+fn remote_item_send_reports_failure() -> Result<()> {
+    let _items = lock_items();
+    let results: Vec<BasicResult> = run(&[
+        "item",
+        "send",
+        &ITEM_ID1[0..7],
+        "--to",
+        "dsc@example.com",
+        "--subject",
+        "dsc-test-send",
+        "--body",
+        "Hello",
+        "--smtp",
+        "dsc-test-missing",
+    ])?;
+    assert_eq!(results.len(), 1);
+    assert!(!results[0].success);
+
+    let sent: SentMails = run(&["item", "sent-mails", &ITEM_ID1[0..7]])?;
+    assert!(sent.items.iter().all(|m| m.subject != "dsc-test-send"));
+    Ok(())
+}